@@ -0,0 +1,66 @@
+//! Generates the solution registry for the main binary.
+//! Every `src/bin/YYYY-DD.rs` file is included as a module and its `SOLUTION` is collected into `SOLUTIONS`,
+//! so `cargo all` and `cargo solve` can run solutions in-process instead of spawning one binary per day.
+use std::{env, fs, io, path::Path};
+
+fn main() -> io::Result<()> {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+
+    println!("cargo:rerun-if-changed={}", bin_dir.display());
+
+    let mut dates: Vec<(u16, u8, String)> = vec![];
+
+    for entry in fs::read_dir(&bin_dir)? {
+        let path = entry?.path();
+
+        let Some((year, day)) = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .filter(|_| path.extension().is_some_and(|ext| ext == "rs"))
+            .and_then(parse_date)
+        else {
+            continue;
+        };
+
+        println!("cargo:rerun-if-changed={}", path.display());
+        dates.push((year, day, path.display().to_string()));
+    }
+
+    dates.sort_unstable();
+
+    let mut registry = String::from("// @generated by build.rs, do not edit.\n\n");
+
+    // the tests of a day already run in its own binary, the test build of `main` leaves the days out so
+    // they don't run a second time.
+    for (year, day, path) in &dates {
+        registry.push_str(&format!(
+            "#[cfg(not(test))]\n#[path = {path:?}]\nmod day_{year}_{day:02};\n"
+        ));
+    }
+
+    registry.push_str("\n#[cfg(not(test))]\n");
+    registry.push_str("pub const SOLUTIONS: &[advent_of_code::template::Solution] = &[\n");
+    for (year, day, _) in &dates {
+        registry.push_str(&format!("    day_{year}_{day:02}::SOLUTION,\n"));
+    }
+    registry.push_str("];\n");
+
+    registry.push_str("\n#[cfg(test)]\n");
+    registry.push_str("pub const SOLUTIONS: &[advent_of_code::template::Solution] = &[];\n");
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("solutions.rs"), registry)
+}
+
+/// Parses file stems like `2023-05` into a year and a day.
+fn parse_date(stem: &str) -> Option<(u16, u8)> {
+    let (year, day) = stem.split_once('-')?;
+    let year = year.parse::<u16>().ok().filter(|year| *year >= 2015)?;
+    let day = day
+        .parse::<u8>()
+        .ok()
+        .filter(|day| (1..=25).contains(day))?;
+
+    Some((year, day))
+}
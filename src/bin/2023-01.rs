@@ -0,0 +1,201 @@
+advent_of_code::solution!(2023, 1);
+
+use std::collections::VecDeque;
+
+const DIGITS: [(&str, u32); 9] = [
+    ("1", 1), ("2", 2), ("3", 3), ("4", 4), ("5", 5), ("6", 6), ("7", 7), ("8", 8), ("9", 9),
+];
+
+const DIGIT_WORDS: [(&str, u32); 9] = [
+    ("one", 1), ("two", 2), ("three", 3), ("four", 4), ("five", 5),
+    ("six", 6), ("seven", 7), ("eight", 8), ("nine", 9),
+];
+
+/// An Aho-Corasick automaton over bytes, finding all (also overlapping) words in one pass.
+struct Automaton {
+    /// The next state for every state and byte, with failure links already resolved.
+    transitions: Vec<[usize; 256]>,
+    /// The words (as length and value) that end in each state.
+    matches: Vec<Vec<(usize, u32)>>,
+    max_len: usize,
+}
+
+impl Automaton {
+    fn new(words: &[(Vec<u8>, u32)]) -> Automaton {
+        const NONE: usize = usize::MAX;
+
+        // Build the trie of all words, state 0 is the root.
+        let mut transitions = vec![[NONE; 256]];
+        let mut matches = vec![Vec::new()];
+
+        for (word, value) in words {
+            let mut state = 0;
+
+            for &byte in word {
+                if transitions[state][byte as usize] == NONE {
+                    transitions.push([NONE; 256]);
+                    matches.push(Vec::new());
+                    transitions[state][byte as usize] = transitions.len() - 1;
+                }
+                state = transitions[state][byte as usize];
+            }
+
+            matches[state].push((word.len(), *value));
+        }
+
+        // Resolve missing transitions through the failure links, breadth first so that
+        // the failure state of every state is complete before the state itself.
+        let mut failure = vec![0; transitions.len()];
+        let mut queue = VecDeque::from([0]);
+
+        while let Some(state) = queue.pop_front() {
+            let fallbacks = if state == 0 { [0; 256] } else { transitions[failure[state]] };
+
+            for (next, fallback) in transitions[state].iter_mut().zip(fallbacks) {
+                if *next == NONE {
+                    *next = fallback;
+                    continue;
+                }
+
+                failure[*next] = fallback;
+                let inherited = matches[fallback].clone();
+                matches[*next].extend(inherited);
+                queue.push_back(*next);
+            }
+        }
+
+        Automaton {
+            transitions,
+            matches,
+            max_len: words.iter().map(|(word, _)| word.len()).max().unwrap_or(0),
+        }
+    }
+
+    /// Returns the value of the word that starts first.
+    fn find_first(&self, haystack: impl Iterator<Item = u8>) -> Option<u32> {
+        let mut state = 0;
+        let mut first: Option<(usize, u32)> = None;
+
+        for (i, byte) in haystack.enumerate() {
+            state = self.transitions[state][byte as usize];
+
+            for &(len, value) in &self.matches[state] {
+                let start = i + 1 - len;
+
+                if first.map_or(true, |(first_start, _)| start < first_start) {
+                    first = Some((start, value));
+                }
+            }
+
+            // Words that end later can't start before the one we found.
+            if first.is_some_and(|(start, _)| i + 2 >= start + self.max_len) {
+                break;
+            }
+        }
+
+        first.map(|(_, value)| value)
+    }
+}
+
+/// Finds the first and last word of a configurable set of words in a line.
+/// Words may overlap, e.g. "twone" starts with two and ends with one.
+struct DigitScanner {
+    forward: Automaton,
+    /// Searches the reversed words in the reversed line.
+    backward: Automaton,
+}
+
+impl DigitScanner {
+    fn new(words: &[(&str, u32)]) -> DigitScanner {
+        let forward: Vec<(Vec<u8>, u32)> = words.iter()
+            .map(|&(word, value)| (word.bytes().collect(), value))
+            .collect();
+
+        let backward: Vec<(Vec<u8>, u32)> = words.iter()
+            .map(|&(word, value)| (word.bytes().rev().collect(), value))
+            .collect();
+
+        DigitScanner {
+            forward: Automaton::new(&forward),
+            backward: Automaton::new(&backward),
+        }
+    }
+
+    /// The value of the word that starts first.
+    fn first(&self, line: &str) -> Option<u32> {
+        self.forward.find_first(line.bytes())
+    }
+
+    /// The value of the word that ends last.
+    fn last(&self, line: &str) -> Option<u32> {
+        self.backward.find_first(line.bytes().rev())
+    }
+}
+
+fn get_line_value(line: &str, scanner: &DigitScanner) -> Option<u32> {
+    Some(scanner.first(line)? * 10 + scanner.last(line)?)
+}
+
+fn get_result(input: &str, scanner: &DigitScanner) -> u32 {
+    // Split input into lines, get the value for each line using the given scanner and sum all the values up at the end
+    input
+        .lines()
+        .filter_map(|l| get_line_value(l, scanner))
+        .sum()
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let scanner = DigitScanner::new(&DIGITS);
+
+    Some(get_result(input, &scanner))
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let scanner = DigitScanner::new(&[DIGITS, DIGIT_WORDS].concat());
+
+    Some(get_result(input, &scanner))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file_part(
+            "examples", DATE, 1,
+        ));
+        assert_eq!(result, Some(142));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file_part(
+            "examples", DATE, 2,
+        ));
+        assert_eq!(result, Some(281));
+    }
+
+    #[test]
+    fn test_overlapping_words() {
+        let scanner = DigitScanner::new(&[DIGITS, DIGIT_WORDS].concat());
+
+        assert_eq!(get_line_value("twone", &scanner), Some(21));
+        assert_eq!(get_line_value("oneight", &scanner), Some(18));
+        assert_eq!(get_line_value("xtwonex", &scanner), Some(21));
+        assert_eq!(get_line_value("sevenine2eightwo", &scanner), Some(72));
+        assert_eq!(get_line_value("abc", &scanner), None);
+    }
+
+    #[test]
+    fn test_custom_words() {
+        let german = DigitScanner::new(&[("eins", 1), ("zwei", 2), ("drei", 3), ("sieben", 7)]);
+        assert_eq!(get_line_value("xzweinsiebenx", &german), Some(27));
+
+        // A word that contains another still counts as starting first.
+        let nested = DigitScanner::new(&[("abcd", 1), ("bc", 2)]);
+        assert_eq!(nested.first("xabcdx"), Some(1));
+        assert_eq!(nested.last("xabcdx"), Some(1));
+        assert_eq!(nested.last("abcdbc"), Some(2));
+    }
+}
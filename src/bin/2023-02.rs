@@ -1,5 +1,9 @@
-advent_of_code::solution!(2);
+advent_of_code::solution!(2023, 2, parse = parse_games);
 
+use advent_of_code::parse::{
+    key_value, labelled, lines, literal, number, one_of, parse_all, separated, ws, ParseError,
+    Parser,
+};
 use std::cmp::max;
 
 struct Cubes {
@@ -28,69 +32,52 @@ impl Cubes {
     }
 }
 
-struct Game {
+pub struct Game {
     id: u32,
     revelations: Vec<Cubes>,
 }
 
-fn parse_revelation(input: &str) -> Cubes {
-    input
-        .split(',')
-        .map(|p| p.trim())
-        .filter_map(|s| {
-            let (num_str, color) = s.split_once(' ')?;
-            let num: u32 = num_str.parse().ok()?;
-
-            Some((num, color))
-        })
-        .fold(
-            Cubes::empty(),
-            |Cubes {
-                 mut red_count,
-                 mut green_count,
-                 mut blue_count,
-             },
-             (number, color)| {
-                match color {
-                    "red" => red_count += number,
-                    "green" => green_count += number,
-                    "blue" => blue_count += number,
+#[derive(Clone, Copy)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
 
-                    _ => (),
-                }
+const COLORS: &[(&str, Color)] = &[
+    ("red", Color::Red),
+    ("green", Color::Green),
+    ("blue", Color::Blue),
+];
 
-                Cubes {
-                    red_count,
-                    green_count,
-                    blue_count,
+/// A revelation like `3 blue, 4 red`.
+fn revelation<'a>() -> impl Parser<'a, Cubes> {
+    separated(ws(number::<u32>()).then(ws(one_of(COLORS))), literal(",")).map(|counts| {
+        counts
+            .into_iter()
+            .fold(Cubes::empty(), |mut cubes, (number, color)| {
+                match color {
+                    Color::Red => cubes.red_count += number,
+                    Color::Green => cubes.green_count += number,
+                    Color::Blue => cubes.blue_count += number,
                 }
-            },
-        )
-}
-
-fn parse_game_id(input: &str) -> Option<u32> {
-    let (game, num) = input.split_once(' ')?;
-    if game != "Game" {
-        return None;
-    }
 
-    num.parse().ok()
+                cubes
+            })
+    })
 }
 
-fn parse_game(input: &str) -> Option<Game> {
-    let (game_def, revelations) = input.split_once(':')?;
-
-    let game_num = parse_game_id(game_def)?;
-    let revs: Vec<Cubes> = revelations.split(';').map(parse_revelation).collect();
-
-    Some(Game {
-        id: game_num,
-        revelations: revs,
-    })
+/// A game like `Game 1: 3 blue, 4 red; 1 red, 2 green`.
+fn game<'a>() -> impl Parser<'a, Game> {
+    key_value(
+        labelled("Game", number()),
+        separated(revelation(), literal(";")),
+    )
+    .map(|(id, revelations)| Game { id, revelations })
 }
 
-fn parse_games(input: &str) -> Vec<Game> {
-    input.lines().filter_map(parse_game).collect()
+fn parse_games(input: &str) -> Result<Vec<Game>, ParseError> {
+    parse_all(lines(game()), input)
 }
 
 fn game_fits(
@@ -140,7 +127,7 @@ fn get_minimum_cubes(
     )
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
+pub fn part_one(games: &[Game]) -> Option<u32> {
     let total_cubes = Cubes {
         red_count: 12,
         green_count: 13,
@@ -148,17 +135,17 @@ pub fn part_one(input: &str) -> Option<u32> {
     };
 
     Some(
-        parse_games(input)
-            .into_iter()
+        games
+            .iter()
             .filter(|g| game_fits(g, &total_cubes))
             .map(|Game { id, .. }| id)
             .sum(),
     )
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(games: &[Game]) -> Option<u32> {
     Some(
-        parse_games(input)
+        games
             .iter()
             .map(get_minimum_cubes)
             .map(|c| c.get_power())
@@ -172,13 +159,25 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(8));
+        let games = parse_games(&advent_of_code::template::read_file("examples", DATE)).unwrap();
+        assert_eq!(part_one(&games), Some(8));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(2286));
+        let games = parse_games(&advent_of_code::template::read_file("examples", DATE)).unwrap();
+        assert_eq!(part_two(&games), Some(2286));
+    }
+
+    #[test]
+    fn test_malformed_games() {
+        let error = parse_games("Game 1: 3 blue\nGame 2: 4 purple, 1 red\n")
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (2, 11));
+        assert_eq!(error.found, "purple");
+
+        let error = parse_games("Game x: 3 blue").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 6));
     }
 }
@@ -0,0 +1,139 @@
+advent_of_code::solution!(2023, 3);
+
+use advent_of_code::grid::{Grid, Position, Span};
+use itertools::Itertools;
+
+struct Number {
+    value: u32,
+    span: Span,
+}
+
+struct Symbol {
+    value: char,
+    position: Position,
+}
+
+struct Schematic {
+    numbers: Vec<Number>,
+    symbols: Vec<Symbol>,
+    /// The id (index into `numbers`) of the number that covers each cell.
+    number_ids: Grid<Option<usize>>,
+}
+
+impl Schematic {
+    fn from_string(input: &str) -> Option<Schematic> {
+        let grid: Grid<char> = input.parse().ok()?;
+
+        let numbers: Vec<Number> = grid.runs(|c| c.is_ascii_digit())
+            .map(|span| {
+                let value = grid.span(&span).iter().collect::<String>().parse().ok()?;
+                Some(Number { value, span })
+            })
+            .collect::<Option<_>>()?;
+
+        let symbols = grid.iter()
+            .filter(|(_, &c)| c != '.' && !c.is_ascii_digit())
+            .map(|(position, &value)| Symbol { value, position })
+            .collect();
+
+        let mut number_ids = Grid::new(grid.width(), grid.height(), None);
+        for (id, number) in numbers.iter().enumerate() {
+            for position in number.span.positions() {
+                number_ids[position] = Some(id);
+            }
+        }
+
+        Some(Schematic {
+            numbers,
+            symbols,
+            number_ids,
+        })
+    }
+
+    /// The ids of the numbers next to a symbol, found by probing the surrounding cells.
+    fn get_adjacent_numbers(&self, symbol: &Symbol) -> Vec<usize> {
+        self.number_ids.neighbours8(symbol.position)
+            .filter_map(|position| self.number_ids[position])
+            .sorted()
+            .dedup()
+            .collect()
+    }
+
+    /// The ids of the numbers next to each symbol, indexed by symbol id.
+    fn get_adjacency_graph(&self) -> Vec<Vec<usize>> {
+        self.symbols.iter()
+            .map(|symbol| self.get_adjacent_numbers(symbol))
+            .collect()
+    }
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let schematic = Schematic::from_string(input)?;
+
+    Some(
+        schematic.get_adjacency_graph()
+            .into_iter()
+            .flatten()
+            .unique()
+            .map(|id| schematic.numbers[id].value)
+            .sum()
+    )
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let schematic = Schematic::from_string(input)?;
+
+    Some(
+        schematic.symbols.iter()
+            .zip(schematic.get_adjacency_graph())
+            .filter(|(symbol, numbers)| symbol.value == '*' && numbers.len() == 2)
+            .map(|(_, numbers)| {
+                numbers.iter()
+                    .map(|&id| schematic.numbers[id].value)
+                    .product::<u32>()
+            })
+            .sum()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", DATE));
+        assert_eq!(result, Some(4361));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", DATE));
+        assert_eq!(result, Some(467835));
+    }
+
+    #[test]
+    fn test_adjacency_graph() {
+        let schematic = Schematic::from_string(&advent_of_code::template::read_file("examples", DATE)).unwrap();
+
+        let graph: Vec<(char, Vec<u32>)> = schematic.symbols.iter()
+            .zip(schematic.get_adjacency_graph())
+            .map(|(symbol, ids)| (symbol.value, ids.iter().map(|&id| schematic.numbers[id].value).collect()))
+            .collect();
+
+        assert_eq!(graph, vec![
+            ('*', vec![467, 35]),
+            ('#', vec![633]),
+            ('*', vec![617]),
+            ('+', vec![592]),
+            ('$', vec![664]),
+            ('*', vec![755, 598]),
+        ]);
+    }
+
+    #[test]
+    fn test_number_touching_a_symbol_twice() {
+        let schematic = Schematic::from_string("123\n*..\n").unwrap();
+        assert_eq!(schematic.get_adjacency_graph(), vec![vec![0]]);
+    }
+}
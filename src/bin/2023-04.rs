@@ -0,0 +1,117 @@
+advent_of_code::solution!(2023, 4, parse = parse_cards);
+
+use std::collections::{BTreeMap, HashSet};
+use advent_of_code::parse::{key_value, labelled, lines, literal, number, numbers, parse_all, ws, ParseError, Parser};
+
+pub struct Card {
+    id: u32,
+    winning_numbers: HashSet<u32>,
+    numbers_you_have: HashSet<u32>,
+}
+
+impl Card {
+    fn get_winning_count(&self) -> u32 {
+        self.numbers_you_have.iter()
+            .filter(|num| self.winning_numbers.contains(num))
+            .count() as u32
+    }
+
+    fn get_score(&self) -> u32 {
+        let winning_count = self.get_winning_count();
+
+        if winning_count > 0 {
+            u32::pow(2, winning_count - 1)
+        } else {
+            0
+        }
+    }
+}
+
+/// A card like `Card 1: 41 48 83 | 83 86  6`.
+fn card<'a>() -> impl Parser<'a, Card> {
+    key_value(
+        labelled("Card", number()),
+        numbers().then_ignore(ws(literal("|"))).then(numbers()),
+    )
+    .map(|(id, (winning, have))| Card {
+        id,
+        winning_numbers: winning.into_iter().collect(),
+        numbers_you_have: have.into_iter().collect(),
+    })
+}
+
+fn parse_cards(input: &str) -> Result<Vec<Card>, ParseError> {
+    parse_all(lines(card()), input)
+}
+
+/// Counts how many instances (the original and all won copies) of each card you end up with, by card id.
+/// Cards only win copies of later cards, so a single pass in id order sees every card's final count
+/// before it hands out copies.
+fn count_copies(cards: &[Card]) -> BTreeMap<u32, u32> {
+    let mut counts: BTreeMap<u32, u32> = cards.iter()
+        .map(|c| (c.id, 1))
+        .collect();
+
+    let mut cards: Vec<&Card> = cards.iter().collect();
+    cards.sort_by_key(|c| c.id);
+
+    for card in cards {
+        let instances = counts[&card.id];
+        let won = (card.id + 1)..(card.id + 1 + card.get_winning_count());
+
+        for count in counts.range_mut(won).map(|(_, count)| count) {
+            *count += instances;
+        }
+    }
+
+    counts
+}
+
+pub fn part_one(cards: &[Card]) -> Option<u32> {
+    Some(
+        cards.iter()
+            .map(|c| c.get_score())
+            .sum()
+    )
+}
+
+pub fn part_two(cards: &[Card]) -> Option<u32> {
+    Some(
+        count_copies(cards).values().sum()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let cards = parse_cards(&advent_of_code::template::read_file("examples", DATE)).unwrap();
+        assert_eq!(part_one(&cards), Some(13));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let cards = parse_cards(&advent_of_code::template::read_file("examples", DATE)).unwrap();
+        assert_eq!(part_two(&cards), Some(30));
+    }
+
+    #[test]
+    fn test_count_copies() {
+        let cards = parse_cards(&advent_of_code::template::read_file("examples", DATE)).unwrap();
+
+        let counts: Vec<(u32, u32)> = count_copies(&cards).into_iter().collect();
+        assert_eq!(counts, vec![(1, 1), (2, 2), (3, 4), (4, 8), (5, 14), (6, 1)]);
+    }
+
+    #[test]
+    fn test_malformed_cards() {
+        let error = parse_cards("Card 1: 41 48 | 83 86\nCard 2: 13 3x | 61 30\n").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 12));
+        assert_eq!(error.found, "3x");
+
+        let error = parse_cards("Card 1: 41 48 83 86").err().unwrap();
+        assert_eq!(error.expected, "`|`");
+    }
+}
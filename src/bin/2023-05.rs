@@ -0,0 +1,157 @@
+advent_of_code::solution!(2023, 5, parse = Almanac::from_string);
+
+use advent_of_code::interval::{Interval, IntervalSet, RangeMap};
+use advent_of_code::parse::{
+    key_value, line, lines, literal, number, numbers, parse_all, sections, word, ws,
+    Failure, ParseError, Parser,
+};
+use itertools::Itertools;
+
+/// A map entry like `50 98 2`: the destination start, the source start and the length of the range.
+fn map_entry<'a>() -> impl Parser<'a, (Interval, i64)> {
+    number().then(ws(number())).then(ws(number()))
+        .map(|((dest_range_start, src_range_start), range_len)| {
+            (Interval::from_len(src_range_start, range_len), dest_range_start)
+        })
+}
+
+/// A header like `seed-to-soil map:` followed by one entry per line.
+fn conversion_map<'a>() -> impl Parser<'a, RangeMap> {
+    line(word().then(literal(" map:")))
+        .ignore_then(lines(map_entry().spanned()))
+        .try_map(|entries| {
+            let mut map = RangeMap::new();
+
+            for ((source, dest_start), entry) in entries {
+                map.insert(source, dest_start).map_err(|e| {
+                    Failure::new(entry, format!("a source range that doesn't overlap {}", e.existing))
+                })?;
+            }
+
+            Ok(map)
+        })
+}
+
+/// The first line like `seeds: 79 14 55 13`, with the part of the line that lists the seeds.
+fn seeds<'a>() -> impl Parser<'a, (Vec<i64>, &'a str)> {
+    line(key_value(literal("seeds"), numbers().spanned()))
+        .try_map(|(_, (seeds, entries))| {
+            if seeds.is_empty() {
+                return Err(Failure::new(entries, "at least one seed"));
+            }
+
+            Ok((seeds, entries))
+        })
+}
+
+pub struct Almanac<'a> {
+    input: &'a str,
+    /// The part of the input that lists the seeds, for errors about them.
+    seeds_span: &'a str,
+    seeds: Vec<i64>,
+    maps: Vec<RangeMap>,
+}
+
+impl Almanac<'_> {
+    fn from_string(input: &str) -> Result<Almanac<'_>, ParseError> {
+        let ((seeds, seeds_span), maps) = parse_all(seeds().then(sections(conversion_map())), input)?;
+
+        Ok(Almanac {
+            input,
+            seeds_span,
+            seeds,
+            maps,
+        })
+    }
+
+    /// The single map from seeds to locations.
+    fn seed_to_location(&self) -> RangeMap {
+        self.maps.iter()
+            .fold(RangeMap::new(), |composed, map| composed.compose(map))
+    }
+
+    fn no_seeds_error(&self) -> ParseError {
+        ParseError::at(self.input, self.seeds_span, "at least one seed range that isn't empty")
+    }
+}
+
+pub fn part_one(almanac: &Almanac<'_>) -> Result<i64, ParseError> {
+    let map = almanac.seed_to_location();
+
+    almanac.seeds
+        .iter()
+        .map(|&s| map.get(s))
+        .min()
+        .ok_or_else(|| almanac.no_seeds_error())
+}
+
+pub fn part_two(almanac: &Almanac<'_>) -> Result<i64, ParseError> {
+    // seeds come in pairs of a start and a length.
+    if almanac.seeds.len() % 2 != 0 {
+        let end = &almanac.seeds_span[almanac.seeds_span.len()..];
+        return Err(ParseError::at(almanac.input, end, "the length of the last seed range"));
+    }
+
+    let seeds: IntervalSet = almanac.seeds.iter()
+        .tuples()
+        .map(|(&start, &len)| Interval::from_len(start, len))
+        .collect();
+
+    almanac.seed_to_location()
+        .map_set(&seeds)
+        .min()
+        .ok_or_else(|| almanac.no_seeds_error())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::template::read_file("examples", DATE);
+        assert_eq!(part_one(&Almanac::from_string(&input).unwrap()), Ok(35));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::template::read_file("examples", DATE);
+        assert_eq!(part_two(&Almanac::from_string(&input).unwrap()), Ok(46));
+    }
+
+    #[test]
+    fn test_dest_ranges() {
+        let input = "seed-to-soil map:\n50 98 2\n52 50 48\n";
+        let map = parse_all(conversion_map(), input).unwrap();
+
+        assert_eq!(map.get(97), 99);
+        assert_eq!(map.get(98), 50);
+        assert_eq!(map.get(100), 100);
+
+        assert_eq!(map.map_interval(Interval::new(40, 100)).intervals(), &[Interval::new(40, 100)]);
+        assert_eq!(map.map_interval(Interval::new(98, 99)).intervals(), &[Interval::new(50, 51)]);
+        assert_eq!(map.map_interval(Interval::new(0, 10)).intervals(), &[Interval::new(0, 10)]);
+    }
+
+    #[test]
+    fn test_malformed_almanac() {
+        let error = Almanac::from_string("seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 50\n").err().unwrap();
+        assert_eq!((error.line, error.expected.as_str()), (5, "a number"));
+
+        let error = Almanac::from_string("seeds: 79 x\n").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 11));
+
+        let error = Almanac::from_string("seeds: 79\n\nseed-to-soil map:\n50 98 2\n50 99 2\n").err().unwrap();
+        assert_eq!(error.line, 5);
+        assert!(error.expected.contains("[98, 100)"));
+
+        let almanac = Almanac::from_string("seeds: 79 14 55
+
+seed-to-soil map:
+50 98 2
+").unwrap();
+        assert_eq!(part_one(&almanac), Ok(14));
+        let error = part_two(&almanac).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 16));
+    }
+}
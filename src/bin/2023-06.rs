@@ -0,0 +1,176 @@
+advent_of_code::solution!(2023, 6);
+
+use std::iter::zip;
+use advent_of_code::parse::{key_value, line, literal, numbers, parse_all, Failure, ParseError, Parser};
+
+struct Race {
+    time: u64,
+    record: u64,
+}
+
+impl Race {
+    fn get_distance(&self, hold_time: u64) -> u128 {
+        hold_time as u128 * (self.time - hold_time) as u128
+    }
+
+    /// The shortest and longest hold times that beat the record, or `None` if no hold time does.
+    /// The distance is a parabola that is symmetric around `time / 2`, so the shortest hold time
+    /// is found with a binary search on its rising half and the longest one mirrors it.
+    fn beat_record_bounds(&self) -> Option<(u64, u64)> {
+        let peak = self.time / 2;
+
+        if self.get_distance(peak) <= self.record as u128 {
+            return None;
+        }
+
+        // Invariant: `low` doesn't beat the record (or is 0), `high` does.
+        let (mut low, mut high) = (0, peak);
+        while low + 1 < high {
+            let mid = low + (high - low) / 2;
+
+            if self.get_distance(mid) > self.record as u128 {
+                high = mid;
+            } else {
+                low = mid;
+            }
+        }
+
+        // `high` is the first time that beats the record.
+        let lower_bound = high;
+        Some((lower_bound, self.time - lower_bound))
+    }
+
+    fn count_ways_to_win(&self) -> u64 {
+        self.beat_record_bounds()
+            .map_or(0, |(lower_bound, upper_bound)| upper_bound - lower_bound + 1)
+    }
+}
+
+/// A line like `Time:      7  15   30`.
+fn row<'a>(label: &'static str) -> impl Parser<'a, Vec<u64>> {
+    line(key_value(literal(label), numbers()))
+        .map(|(_, numbers)| numbers)
+}
+
+/// A line like `Time:      7  15   30` read as a single number with badly kerned digits, `71530`.
+fn kerned_row<'a>(label: &'static str) -> impl Parser<'a, u64> {
+    line(key_value(literal(label), numbers::<u64>().spanned()))
+        .try_map(|(_, (_, digits))| {
+            digits.split_whitespace()
+                .collect::<String>()
+                .parse()
+                .map_err(|_| Failure::new(digits, "digits that form a number that fits `u64`"))
+        })
+}
+
+fn parse_races(input: &str) -> Result<Vec<Race>, ParseError> {
+    let races = row("Time").then(row("Distance").spanned())
+        .try_map(|(times, (records, records_line))| {
+            if times.len() != records.len() {
+                let expected = format!("a record for each of the {} races", times.len());
+                return Err(Failure::new(records_line, expected));
+            }
+
+            Ok(
+                zip(times, records)
+                    .map(|(time, record)| Race {
+                        time,
+                        record,
+                    })
+                    .collect()
+            )
+        });
+
+    parse_all(races, input)
+}
+
+fn parse_single_race(input: &str) -> Result<Race, ParseError> {
+    let (time, record) = parse_all(kerned_row("Time").then(kerned_row("Distance")), input)?;
+
+    Ok(Race { time, record })
+}
+
+pub fn part_one(input: &str) -> Result<u64, ParseError> {
+    let races = parse_races(input)?;
+
+    Ok(
+        races.iter()
+            .map(Race::count_ways_to_win)
+            .product()
+    )
+}
+
+pub fn part_two(input: &str) -> Result<u64, ParseError> {
+    let race = parse_single_race(input)?;
+
+    Ok(race.count_ways_to_win())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", DATE));
+        assert_eq!(result, Ok(288));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", DATE));
+        assert_eq!(result, Ok(71503));
+    }
+
+    #[test]
+    fn test_perfect_square_discriminant() {
+        // 4 * 21 = 100 - 16, so holding for 3 or 7 exactly ties the record.
+        let race = Race { time: 10, record: 21 };
+        assert_eq!(race.beat_record_bounds(), Some((4, 6)));
+
+        let race = Race { time: 30, record: 200 };
+        assert_eq!(race.beat_record_bounds(), Some((11, 19)));
+    }
+
+    #[test]
+    fn test_zero_winning_races() {
+        assert_eq!(Race { time: 10, record: 25 }.count_ways_to_win(), 0);
+        assert_eq!(Race { time: 0, record: 0 }.count_ways_to_win(), 0);
+        assert_eq!(Race { time: 1, record: 0 }.count_ways_to_win(), 0);
+    }
+
+    #[test]
+    fn test_large_values() {
+        let race = Race { time: u64::MAX, record: 0 };
+        assert_eq!(race.beat_record_bounds(), Some((1, u64::MAX - 1)));
+
+        let race = Race { time: u64::MAX, record: u64::MAX };
+        assert_eq!(race.beat_record_bounds(), Some((2, u64::MAX - 2)));
+
+        // Beyond 2^53, where f64 can't tell neighbouring values apart.
+        let race = Race { time: (1 << 60) + 1, record: 1 << 60 };
+        assert_eq!(race.beat_record_bounds(), Some((2, (1 << 60) - 1)));
+    }
+
+    #[test]
+    fn test_matches_brute_force() {
+        for time in 0..60 {
+            for record in 0..(time * time / 4 + 2) {
+                let race = Race { time, record };
+                let expected = (0..=time).filter(|&h| h * (time - h) > record).count() as u64;
+
+                assert_eq!(race.count_ways_to_win(), expected, "time {time}, record {record}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_malformed_races() {
+        let error = part_one("Time: 7 15 30\nDistance: 9 40\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.expected, "a record for each of the 3 races");
+
+        let error = part_two("Time: 7 15 30\nDistnace: 9 40 200\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.found.as_str()), (2, 1, "Distnace"));
+    }
+}
@@ -0,0 +1,199 @@
+advent_of_code::solution!(2023, 7);
+
+use std::cmp::Ordering;
+use advent_of_code::parse::{parse_lines, ParseError};
+use itertools::Itertools;
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+#[derive(PartialEq, Eq, Hash)]
+struct Card(char);
+
+/// The rules that hands are ranked by.
+struct Ruleset {
+    /// All card labels, from weakest to strongest.
+    card_order: &'static str,
+    /// A card that acts like whatever card makes the strongest hand.
+    wildcard: Option<Card>,
+}
+
+const STANDARD_RULES: Ruleset = Ruleset {
+    card_order: "23456789TJQKA",
+    wildcard: None,
+};
+
+const JOKER_RULES: Ruleset = Ruleset {
+    card_order: "J23456789TQKA",
+    wildcard: Some(Card('J')),
+};
+
+impl Ruleset {
+    fn cmp_cards(&self, a: &Card, b: &Card) -> Ordering {
+        // cards are checked against the labels when a hand is parsed.
+        let strength = |card: &Card| self.card_order.find(card.0)
+            .expect("a card with a known label");
+
+        strength(a).cmp(&strength(b))
+    }
+
+    fn cmp_hands(&self, a: &Hand, b: &Hand) -> Ordering {
+        a.get_hand_type(self).cmp(&b.get_hand_type(self))
+            .then_with(|| {
+                a.cards.iter()
+                    .zip(&b.cards)
+                    .map(|(a, b)| self.cmp_cards(a, b))
+                    .find(|ordering| ordering.is_ne())
+                    .unwrap_or(Ordering::Equal)
+            })
+    }
+
+    fn is_wildcard(&self, card: &Card) -> bool {
+        self.wildcard.as_ref() == Some(card)
+    }
+}
+
+struct Hand {
+    cards: Vec<Card>,
+    bid: u32,
+}
+
+impl Hand {
+    fn from_string(input: &str) -> Result<Hand, ParseError> {
+        let (cards, bid_str) = input.split_whitespace()
+            .collect_tuple()
+            .ok_or_else(|| ParseError::new(input, input, "cards and a bid"))?;
+
+        if cards.chars().count() != 5 {
+            return Err(ParseError::new(input, cards, "five cards"));
+        }
+
+        if let Some((idx, label)) = cards.char_indices()
+            .find(|&(_, label)| !STANDARD_RULES.card_order.contains(label))
+        {
+            let card = &cards[idx..idx + label.len_utf8()];
+            return Err(ParseError::new(input, card, "a card label, one of `23456789TJQKA`"));
+        }
+
+        let bid = bid_str.parse()
+            .map_err(|_| ParseError::new(input, bid_str, "a bid"))?;
+
+        Ok(Hand {
+            cards: cards.chars().map(Card).collect_vec(),
+            bid,
+        })
+    }
+
+    fn get_hand_type(&self, rules: &Ruleset) -> HandType {
+        let (wildcards, cards): (Vec<&Card>, Vec<&Card>) = self.cards.iter()
+            .partition(|&card| rules.is_wildcard(card));
+
+        let mut sorted_counts = cards.into_iter()
+            .counts()
+            .into_values()
+            .sorted_by(|a, b| a.cmp(b).reverse())
+            .collect_vec();
+
+        // Wildcards always make the best hand by joining the largest group.
+        match sorted_counts.first_mut() {
+            Some(count) => *count += wildcards.len(),
+            None => sorted_counts.push(wildcards.len()),
+        }
+
+        // Thanks random reddit person for this approach
+        // I wish I could figure this sorta thing out by myself but I can't unfortunately
+        let diff = (sorted_counts[0] as i32) - sorted_counts.len() as i32;
+
+        match diff {
+            4 => HandType::FiveOfAKind,
+            2 => HandType::FourOfAKind,
+            1 => HandType::FullHouse,
+            0 => HandType::ThreeOfAKind,
+            -1 => HandType::TwoPair,
+            -2 => HandType::OnePair,
+
+            _ => HandType::HighCard,
+        }
+    }
+}
+
+fn total_winnings(input: &str, rules: &Ruleset) -> Result<u32, ParseError> {
+    Ok(
+        parse_lines(input, Hand::from_string)?
+            .into_iter()
+            .sorted_by(|a, b| rules.cmp_hands(a, b))
+            .enumerate()
+            .map(|(i, hand)| hand.bid * (i as u32 + 1))
+            .sum()
+    )
+}
+
+pub fn part_one(input: &str) -> Result<u32, ParseError> {
+    total_winnings(input, &STANDARD_RULES)
+}
+
+pub fn part_two(input: &str) -> Result<u32, ParseError> {
+    total_winnings(input, &JOKER_RULES)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", DATE));
+        assert_eq!(result, Ok(6440));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", DATE));
+        assert_eq!(result, Ok(5905));
+    }
+
+    #[test]
+    fn test_hand_types() {
+        let hand_type = |cards: &str, rules: &Ruleset| {
+            Hand::from_string(&format!("{cards} 1")).unwrap().get_hand_type(rules)
+        };
+
+        assert_eq!(hand_type("KTJJT", &STANDARD_RULES), HandType::TwoPair);
+        assert_eq!(hand_type("KTJJT", &JOKER_RULES), HandType::FourOfAKind);
+        assert_eq!(hand_type("JJJJJ", &JOKER_RULES), HandType::FiveOfAKind);
+        assert_eq!(hand_type("2345J", &JOKER_RULES), HandType::OnePair);
+        assert_eq!(hand_type("2233J", &JOKER_RULES), HandType::FullHouse);
+    }
+
+    #[test]
+    fn test_jokers_are_weakest() {
+        let jack = Hand::from_string("JKKK2 1").unwrap();
+        let queen = Hand::from_string("QQQQ2 1").unwrap();
+
+        assert_eq!(JOKER_RULES.cmp_hands(&jack, &queen), Ordering::Less);
+        assert_eq!(STANDARD_RULES.cmp_hands(&jack, &queen), Ordering::Less);
+        assert_eq!(STANDARD_RULES.cmp_cards(&Card('J'), &Card('Q')), Ordering::Less);
+        assert_eq!(JOKER_RULES.cmp_cards(&Card('J'), &Card('2')), Ordering::Less);
+    }
+
+    #[test]
+    fn test_malformed_hands() {
+        let error = part_one("32T3K 765\nT55J5 x84\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 7));
+
+        let error = part_one("32T3 765").unwrap_err();
+        assert_eq!(error.expected, "five cards");
+
+        let error = part_one("32T3K 765\n32X3K 765\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.found, "X");
+    }
+}
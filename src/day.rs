@@ -80,38 +80,179 @@ impl Display for DayFromStrError {
 
 /* -------------------------------------------------------------------------- */
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
-pub fn all_days() -> AllDays {
-    AllDays::new()
+/// A set of days of advent.
+///
+/// Parses from a comma separated list of days and ranges, where ranges may be open on either side.
+///
+/// ```
+/// # use advent_of_code::{Day, DaySet};
+/// let days: DaySet = "1-3,7,24-".parse().unwrap();
+/// let days: Vec<u8> = days.iter().map(Day::into_inner).collect();
+/// assert_eq!(days, [1, 2, 3, 7, 24, 25]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Hash)]
+pub struct DaySet(u32);
+
+impl DaySet {
+    /// The set that contains no day.
+    pub const EMPTY: Self = Self(0);
+    /// The set that contains every day from the 1st to the 25th.
+    pub const ALL: Self = Self(((1 << 25) - 1) << 1);
+
+    /// Creates the set of the days from `first` to `last`, both included.
+    pub fn range(first: Day, last: Day) -> Self {
+        if first > last {
+            return Self::EMPTY;
+        }
+        Self((u32::MAX >> (31 - last.0)) & (u32::MAX << first.0))
+    }
+
+    /// Returns `true` if the set contains the day.
+    pub fn contains(self, day: Day) -> bool {
+        self.0 & (1 << day.0) != 0
+    }
+
+    /// Adds a day to the set.
+    pub fn insert(&mut self, day: Day) {
+        self.0 |= 1 << day.0;
+    }
+
+    /// Returns `true` if the set contains no day.
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the number of days in the set.
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Returns the days that are in either set.
+    #[must_use]
+    pub fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Returns the days that are in both sets.
+    #[must_use]
+    pub fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    /// Returns the days of advent that are not in the set.
+    #[must_use]
+    pub fn complement(self) -> Self {
+        Self(!self.0 & Self::ALL.0)
+    }
+
+    /// Returns the last day in the set.
+    pub fn last(self) -> Option<Day> {
+        (!self.is_empty()).then(|| Day(31 - self.0.leading_zeros() as u8))
+    }
+
+    /// An iterator that yields the days of the set in order.
+    pub fn iter(self) -> Days {
+        Days { remaining: self }
+    }
 }
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
-pub struct AllDays {
-    current: u8,
+impl IntoIterator for DaySet {
+    type Item = Day;
+    type IntoIter = Days;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
 }
 
-impl AllDays {
-    #[allow(clippy::new_without_default)]
-    pub fn new() -> Self {
-        Self { current: 1 }
+impl FromIterator<Day> for DaySet {
+    fn from_iter<I: IntoIterator<Item = Day>>(iter: I) -> Self {
+        let mut set = Self::EMPTY;
+        for day in iter {
+            set.insert(day);
+        }
+        set
     }
 }
 
-impl Iterator for AllDays {
+impl FromStr for DaySet {
+    type Err = DaySetFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',').try_fold(Self::EMPTY, |set, item| {
+            let item = item.trim();
+            let invalid = || DaySetFromStrError(item.into());
+            let bound = |s: &str, default: Day| match s.trim() {
+                "" => Ok(default),
+                s => s.parse().map_err(|_| invalid()),
+            };
+
+            if item.is_empty() {
+                return Err(invalid());
+            }
+
+            let (first, last) = item.split_once('-').unwrap_or((item, item));
+            let (first, last) = (bound(first, Day(1))?, bound(last, Day(25))?);
+
+            if first > last {
+                return Err(invalid());
+            }
+
+            Ok(set.union(Self::range(first, last)))
+        })
+    }
+}
+
+/// An error which can be returned when parsing a [`DaySet`], holds the day or range that is invalid.
+#[derive(Debug)]
+pub struct DaySetFromStrError(pub String);
+
+impl Error for DaySetFromStrError {}
+
+impl Display for DaySetFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid day or range `{}`, expecting days between 1 and 25 like `1-5,7,12-`",
+            self.0
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An iterator that yields every day of advent from the 1st to the 25th.
+pub fn all_days() -> Days {
+    DaySet::ALL.iter()
+}
+
+/// An iterator that yields the days of a [`DaySet`] in order.
+pub struct Days {
+    remaining: DaySet,
+}
+
+impl Iterator for Days {
     type Item = Day;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > 25 {
+        if self.remaining.is_empty() {
             return None;
         }
-        // NOTE: the iterator starts at 1 and we have verified that the value is not above 25.
-        let day = Day(self.current);
-        self.current += 1;
+        // NOTE: only the bits 1 to 25 of a set can be set, so the lowest one is a valid day.
+        let day = Day(self.remaining.0.trailing_zeros() as u8);
+        self.remaining.0 &= self.remaining.0 - 1;
 
         Some(day)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.remaining.len();
+        (len, Some(len))
+    }
 }
 
+impl ExactSizeIterator for Days {}
+
 /* -------------------------------------------------------------------------- */
 
 /// Creates a [`Day`] value in a const context.
@@ -134,7 +275,7 @@ macro_rules! day {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{all_days, Day};
+    use super::{all_days, Day, DaySet};
 
     #[test]
     fn all_days_iterator() {
@@ -167,6 +308,48 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    fn days(set: DaySet) -> Vec<u8> {
+        set.iter().map(Day::into_inner).collect()
+    }
+
+    #[test]
+    fn parses_day_sets() {
+        let parse = |s: &str| s.parse::<DaySet>().map(days).ok();
+
+        assert_eq!(parse("7"), Some(vec![7]));
+        assert_eq!(parse("1-3, 7"), Some(vec![1, 2, 3, 7]));
+        assert_eq!(parse("22-"), Some(vec![22, 23, 24, 25]));
+        assert_eq!(parse("-2,2"), Some(vec![1, 2]));
+        assert_eq!(parse("5-3"), None);
+        assert_eq!(parse("-").map(|days| days.len()), Some(25));
+        assert_eq!(parse(""), None);
+        assert_eq!(parse("1,,2"), None);
+        assert_eq!(parse("0-3"), None);
+        assert_eq!(parse("24-26"), None);
+        assert_eq!(parse("x"), None);
+    }
+
+    #[test]
+    fn combines_day_sets() {
+        let first: DaySet = "1-10".parse().unwrap();
+        let second: DaySet = "5-15".parse().unwrap();
+
+        assert_eq!(days(first.intersection(second)), [5, 6, 7, 8, 9, 10]);
+        assert_eq!(first.union(second), "1-15".parse().unwrap());
+        assert_eq!(first.complement(), "11-".parse().unwrap());
+        assert_eq!(first.last(), Some(Day(10)));
+        assert_eq!(DaySet::EMPTY.last(), None);
+        assert_eq!(DaySet::ALL.len(), 25);
+        assert_eq!(all_days().len(), 25);
+    }
+
+    /// The example of the [`DaySet`] documentation, doctests are disabled for this crate.
+    #[test]
+    fn day_set_example() {
+        let set: DaySet = "1-3,7,24-".parse().unwrap();
+        assert_eq!(days(set), [1, 2, 3, 7, 24, 25]);
+    }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -0,0 +1,502 @@
+//! Rectangular 2D grids, as found in many puzzle inputs.
+//!
+//! ```
+//! # use advent_of_code::grid::{Grid, Position};
+//! let grid: Grid<char> = "467..\n...*.\n..35.".parse().unwrap();
+//!
+//! let numbers: Vec<u32> = grid
+//!     .runs(|c| c.is_ascii_digit())
+//!     .map(|span| grid.span(&span).iter().collect::<String>().parse().unwrap())
+//!     .collect();
+//! assert_eq!(numbers, vec![467, 35]);
+//!
+//! let star = Position::new(1, 3);
+//! assert_eq!(grid.neighbours8(star).filter(|&p| grid[p].is_ascii_digit()).count(), 3);
+//! ```
+use std::error::Error;
+use std::fmt::Display;
+use std::ops::{Index, IndexMut, Range};
+use std::str::FromStr;
+
+/// The offsets of the four orthogonal neighbours of a cell, as `(row, col)`.
+const NEIGHBOURS_4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// The offsets of all eight neighbours of a cell, including diagonals, as `(row, col)`.
+const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// The position of a cell in a [`Grid`]. Positions order row by row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Position {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Position {
+    pub const fn new(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+
+    /// Moves the position by an offset, returns [`None`] if it would leave the non-negative quadrant.
+    pub fn offset(&self, rows: isize, cols: isize) -> Option<Position> {
+        Some(Position::new(
+            self.row.checked_add_signed(rows)?,
+            self.col.checked_add_signed(cols)?,
+        ))
+    }
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.row, self.col)
+    }
+}
+
+/// A horizontal run of cells in a single row, e.g. the digits of a number.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Span {
+    pub row: usize,
+    pub cols: Range<usize>,
+}
+
+impl Span {
+    pub fn positions(&self) -> impl Iterator<Item = Position> + '_ {
+        self.cols.clone().map(|col| Position::new(self.row, col))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Parses a grid from lines of text, converting every character with `parse_cell`.
+    /// All lines must have the same number of characters.
+    pub fn parse_with(
+        input: &str,
+        mut parse_cell: impl FnMut(char) -> T,
+    ) -> Result<Self, GridParseError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for (row, line) in input.lines().enumerate() {
+            let before = cells.len();
+            cells.extend(line.chars().map(&mut parse_cell));
+            let len = cells.len() - before;
+
+            match width {
+                Some(expected) if expected != len => {
+                    return Err(GridParseError::RaggedRow {
+                        row,
+                        expected,
+                        found: len,
+                    })
+                }
+                _ => width = Some(len),
+            }
+
+            height += 1;
+        }
+
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Position) -> bool {
+        pos.row < self.height && pos.col < self.width
+    }
+
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        self.contains(pos).then(|| &self.cells[self.index_of(pos)])
+    }
+
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        if !self.contains(pos) {
+            return None;
+        }
+
+        let idx = self.index_of(pos);
+        Some(&mut self.cells[idx])
+    }
+
+    /// Moves a position by an offset, returns [`None`] if the result is outside of the grid.
+    pub fn offset(&self, pos: Position, rows: isize, cols: isize) -> Option<Position> {
+        pos.offset(rows, cols).filter(|&p| self.contains(p))
+    }
+
+    /// The orthogonal neighbours of a position that are inside the grid.
+    pub fn neighbours4(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        NEIGHBOURS_4
+            .iter()
+            .filter_map(move |&(rows, cols)| self.offset(pos, rows, cols))
+    }
+
+    /// The orthogonal and diagonal neighbours of a position that are inside the grid.
+    pub fn neighbours8(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        NEIGHBOURS_8
+            .iter()
+            .filter_map(move |&(rows, cols)| self.offset(pos, rows, cols))
+    }
+
+    /// All positions of the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| Position::new(row, col)))
+    }
+
+    /// All cells of the grid with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        (row < self.height).then(|| &self.cells[row * self.width..(row + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics for a width of zero, those grids have no cells to iterate anyway.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The cells of a column from top to bottom, empty if the column is outside of the grid.
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        let cells = if col < self.width {
+            &self.cells[col..]
+        } else {
+            &[]
+        };
+
+        cells.iter().step_by(self.width.max(1))
+    }
+
+    /// The cells of a rectangular region with their positions, row by row.
+    /// Parts of the region outside of the grid are skipped.
+    pub fn region(
+        &self,
+        rows: Range<usize>,
+        cols: Range<usize>,
+    ) -> impl Iterator<Item = (Position, &T)> {
+        let rows = rows.start..rows.end.min(self.height);
+        let cols = cols.start..cols.end.min(self.width);
+
+        rows.flat_map(move |row| {
+            cols.clone().map(move |col| {
+                let pos = Position::new(row, col);
+                (pos, &self[pos])
+            })
+        })
+    }
+
+    /// The maximal horizontal runs of cells that match `predicate`, row by row.
+    pub fn runs<'a>(
+        &'a self,
+        mut predicate: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Span> + 'a {
+        self.rows().enumerate().flat_map(move |(row, cells)| {
+            let mut spans = Vec::new();
+            let mut start = None;
+
+            for (col, cell) in cells.iter().enumerate() {
+                match (predicate(cell), start) {
+                    (true, None) => start = Some(col),
+                    (false, Some(s)) => {
+                        spans.push(Span { row, cols: s..col });
+                        start = None;
+                    }
+                    _ => {}
+                }
+            }
+
+            if let Some(s) = start {
+                spans.push(Span {
+                    row,
+                    cols: s..cells.len(),
+                });
+            }
+
+            spans
+        })
+    }
+
+    /// The cells covered by a span.
+    ///
+    /// # Panics
+    /// Panics if the span is outside of the grid.
+    pub fn span(&self, span: &Span) -> &[T] {
+        let start = self.index_of(Position::new(span.row, span.cols.start));
+        &self.cells[start..start + span.cols.len()]
+    }
+
+    /// Converts every cell, keeping the shape of the grid.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    fn index_of(&self, pos: Position) -> usize {
+        pos.row * self.width + pos.col
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("position {pos} is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut Self::Output {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("position {pos} is outside of the grid"))
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = GridParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, |c| c)
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for cells in self.rows() {
+            for cell in cells {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// An error which can be returned when parsing a [`Grid`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum GridParseError {
+    /// A line doesn't have as many cells as the lines before it.
+    RaggedRow {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl Error for GridParseError {}
+
+impl Display for GridParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridParseError::RaggedRow {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "row {row} has {found} cells, expecting {expected} like the rows before it"
+            ),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Grid, GridParseError, Position, Span};
+
+    const EXAMPLE: &str = "467..114..\n...*......\n..35..633.\n";
+
+    fn example() -> Grid<char> {
+        EXAMPLE.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_grids() {
+        let grid = example();
+
+        assert_eq!((grid.width(), grid.height()), (10, 3));
+        assert_eq!(grid[Position::new(1, 3)], '*');
+        assert_eq!(grid.to_string(), EXAMPLE);
+
+        let digits = Grid::parse_with("12\n34", |c| c.to_digit(10).unwrap());
+        assert_eq!(digits.unwrap()[Position::new(1, 0)], 3);
+
+        let empty: Grid<char> = "".parse().unwrap();
+        assert_eq!((empty.width(), empty.height()), (0, 0));
+        assert_eq!(empty.rows().count(), 0);
+    }
+
+    #[test]
+    fn rejects_ragged_rows() {
+        let error = "abc\nab\nabc".parse::<Grid<char>>().unwrap_err();
+
+        assert_eq!(
+            error,
+            GridParseError::RaggedRow {
+                row: 1,
+                expected: 3,
+                found: 2
+            }
+        );
+    }
+
+    #[test]
+    fn checks_bounds() {
+        let mut grid = example();
+
+        assert_eq!(grid.get(Position::new(3, 0)), None);
+        assert_eq!(grid.get(Position::new(0, 10)), None);
+        assert_eq!(grid.offset(Position::new(0, 0), -1, 0), None);
+        assert_eq!(
+            grid.offset(Position::new(0, 0), 2, 9),
+            Some(Position::new(2, 9))
+        );
+
+        *grid.get_mut(Position::new(0, 0)).unwrap() = '#';
+        grid[Position::new(0, 1)] = '#';
+        assert_eq!(grid.row(0).unwrap()[..3], ['#', '#', '7']);
+        assert_eq!(grid.row(3), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "outside of the grid")]
+    fn panics_when_indexing_outside() {
+        let _ = example()[Position::new(5, 5)];
+    }
+
+    #[test]
+    fn finds_neighbours() {
+        let grid = example();
+
+        let corner: Vec<Position> = grid.neighbours8(Position::new(0, 0)).collect();
+        assert_eq!(
+            corner,
+            vec![
+                Position::new(0, 1),
+                Position::new(1, 0),
+                Position::new(1, 1)
+            ]
+        );
+
+        assert_eq!(grid.neighbours4(Position::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8(Position::new(1, 1)).count(), 8);
+        assert_eq!(grid.neighbours4(Position::new(2, 9)).count(), 2);
+    }
+
+    #[test]
+    fn iterates_rows_columns_and_regions() {
+        let grid = example();
+
+        assert_eq!(grid.rows().count(), 3);
+        assert_eq!(grid.column(3).collect::<String>(), ".*5");
+        assert_eq!(grid.column(10).count(), 0);
+        assert_eq!(grid.positions().count(), 30);
+        assert_eq!(grid.iter().nth(11), Some((Position::new(1, 1), &'.')));
+
+        let region: String = grid.region(1..5, 2..5).map(|(_, c)| c).collect();
+        assert_eq!(region, ".*.35.");
+    }
+
+    #[test]
+    fn extracts_runs() {
+        let grid = example();
+        let spans: Vec<Span> = grid.runs(|c| c.is_ascii_digit()).collect();
+
+        assert_eq!(
+            spans,
+            vec![
+                Span { row: 0, cols: 0..3 },
+                Span { row: 0, cols: 5..8 },
+                Span { row: 2, cols: 2..4 },
+                Span { row: 2, cols: 6..9 },
+            ]
+        );
+
+        let numbers: Vec<String> = spans
+            .iter()
+            .map(|span| grid.span(span).iter().collect())
+            .collect();
+        assert_eq!(numbers, ["467", "114", "35", "633"]);
+
+        assert_eq!(
+            spans[2].positions().collect::<Vec<_>>(),
+            vec![Position::new(2, 2), Position::new(2, 3)]
+        );
+
+        let trailing: Grid<char> = "..12".parse().unwrap();
+        assert_eq!(
+            trailing.runs(|c| *c != '.').collect::<Vec<_>>(),
+            vec![Span { row: 0, cols: 2..4 }]
+        );
+    }
+
+    #[test]
+    fn maps_cells() {
+        let grid = example().map(|c| c.is_ascii_digit());
+
+        assert_eq!((grid.width(), grid.height()), (10, 3));
+        assert!(grid[Position::new(0, 0)]);
+        assert!(!grid[Position::new(1, 3)]);
+    }
+
+    /// The example of the module documentation, doctests are disabled for this crate.
+    #[test]
+    fn module_example() {
+        let grid: Grid<char> = "467..\n...*.\n..35.".parse().unwrap();
+
+        let numbers: Vec<u32> = grid
+            .runs(|c| c.is_ascii_digit())
+            .map(|span| grid.span(&span).iter().collect::<String>().parse().unwrap())
+            .collect();
+        assert_eq!(numbers, vec![467, 35]);
+
+        let star = Position::new(1, 3);
+        let digits = grid.neighbours8(star).filter(|&p| grid[p].is_ascii_digit());
+        assert_eq!(digits.count(), 3);
+    }
+}
@@ -0,0 +1,655 @@
+//! Half-open intervals of integers, sets of intervals and maps that translate intervals.
+//!
+//! ```
+//! # use advent_of_code::interval::{Interval, IntervalSet, RangeMap};
+//! let mut map = RangeMap::new();
+//! map.insert(Interval::new(98, 100), 50).unwrap();
+//! map.insert(Interval::new(50, 98), 52).unwrap();
+//!
+//! let seeds: IntervalSet = [Interval::from_len(79, 14), Interval::from_len(55, 13)].into_iter().collect();
+//! assert_eq!(map.map_set(&seeds).min(), Some(57));
+//! ```
+use std::error::Error;
+use std::fmt::Display;
+use std::ops::Range;
+
+/// An interval of integers that includes `start` and excludes `end`.
+/// Intervals with `start >= end` are empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub const fn new(start: i64, end: i64) -> Self {
+        Self { start, end }
+    }
+
+    /// Creates the interval of `len` values beginning at `start`. The end saturates at [`i64::MAX`].
+    pub const fn from_len(start: i64, len: i64) -> Self {
+        Self::new(start, start.saturating_add(len))
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    /// The number of values in the interval.
+    pub const fn len(&self) -> i64 {
+        if self.is_empty() {
+            0
+        } else {
+            self.end.saturating_sub(self.start)
+        }
+    }
+
+    pub const fn contains(&self, value: i64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// Returns the values that are in both intervals, the result might be empty.
+    pub fn intersection(&self, other: &Interval) -> Interval {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// Moves the interval by `offset`, its bounds saturate at the limits of [`i64`].
+    pub const fn shift(&self, offset: i64) -> Interval {
+        Interval::new(
+            self.start.saturating_add(offset),
+            self.end.saturating_add(offset),
+        )
+    }
+}
+
+impl From<Range<i64>> for Interval {
+    fn from(range: Range<i64>) -> Self {
+        Self::new(range.start, range.end)
+    }
+}
+
+impl From<Interval> for Range<i64> {
+    fn from(interval: Interval) -> Self {
+        interval.start..interval.end
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}, {})", self.start, self.end)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A set of integers, stored as sorted intervals.
+/// Overlapping and adjacent intervals are coalesced, so equal sets have equal intervals.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds all values of an interval to the set.
+    pub fn insert(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+
+        // the intervals that overlap or touch the new one are replaced by their union.
+        let first = self.intervals.partition_point(|i| i.end < interval.start);
+        let last = self.intervals.partition_point(|i| i.start <= interval.end);
+
+        let mut merged = interval;
+
+        if first < last {
+            merged.start = merged.start.min(self.intervals[first].start);
+            merged.end = merged.end.max(self.intervals[last - 1].end);
+        }
+
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let idx = self.intervals.partition_point(|i| i.end <= value);
+        self.intervals.get(idx).is_some_and(|i| i.contains(value))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The number of values in the set.
+    pub fn len(&self) -> i64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    /// The smallest value in the set.
+    pub fn min(&self) -> Option<i64> {
+        self.intervals.first().map(|i| i.start)
+    }
+
+    /// The largest value in the set.
+    pub fn max(&self) -> Option<i64> {
+        self.intervals.last().map(|i| i.end - 1)
+    }
+
+    /// The coalesced intervals of the set, in ascending order.
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Interval> + '_ {
+        self.intervals.iter().copied()
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut merged = [self.intervals.as_slice(), other.intervals.as_slice()].concat();
+        merged.sort_unstable();
+
+        let mut set = IntervalSet::new();
+        merged.into_iter().for_each(|i| set.push_sorted(i));
+        set
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut set = IntervalSet::new();
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(a), Some(b)) = (self.intervals.get(i), other.intervals.get(j)) {
+            set.push_sorted(a.intersection(b));
+
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        set
+    }
+
+    /// Returns the values of this set that are not in `other`.
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut set = IntervalSet::new();
+        let mut j = 0;
+
+        for a in &self.intervals {
+            while other.intervals.get(j).is_some_and(|b| b.end <= a.start) {
+                j += 1;
+            }
+
+            let mut start = a.start;
+
+            for b in other.intervals[j..].iter().take_while(|b| b.start < a.end) {
+                set.push_sorted(Interval::new(start, b.start));
+                start = start.max(b.end);
+            }
+
+            set.push_sorted(Interval::new(start, a.end));
+        }
+
+        set
+    }
+
+    /// Appends an interval that doesn't start before the last one, coalescing them if they touch.
+    fn push_sorted(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+
+        match self.intervals.last_mut() {
+            Some(last) if last.end >= interval.start => last.end = last.end.max(interval.end),
+            _ => self.intervals.push(interval),
+        }
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<T: IntoIterator<Item = Interval>>(iter: T) -> Self {
+        let mut intervals: Vec<Interval> = iter.into_iter().collect();
+        intervals.sort_unstable();
+
+        let mut set = IntervalSet::new();
+        intervals.into_iter().for_each(|i| set.push_sorted(i));
+        set
+    }
+}
+
+impl Extend<Interval> for IntervalSet {
+    fn extend<T: IntoIterator<Item = Interval>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|i| self.insert(i));
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A map that moves the values of some source intervals by a fixed offset each and leaves all other values unchanged.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RangeMap {
+    /// Source intervals and their offsets, sorted and without overlaps.
+    entries: Vec<(Interval, i64)>,
+}
+
+impl RangeMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maps the values of `source` to the values starting at `dest_start`.
+    /// Returns an error if `source` overlaps the source of another entry.
+    pub fn insert(&mut self, source: Interval, dest_start: i64) -> Result<(), OverlapError> {
+        if source.is_empty() {
+            return Ok(());
+        }
+
+        let idx = self.entries.partition_point(|(s, _)| s.end <= source.start);
+
+        if let Some(&(existing, _)) = self.entries.get(idx).filter(|(s, _)| s.overlaps(&source)) {
+            return Err(OverlapError { existing, source });
+        }
+
+        self.entries
+            .insert(idx, (source, dest_start - source.start));
+        Ok(())
+    }
+
+    /// The source and destination interval of every entry, in ascending order of the sources.
+    pub fn entries(&self) -> impl Iterator<Item = (Interval, Interval)> + '_ {
+        self.entries
+            .iter()
+            .map(|&(source, offset)| (source, source.shift(offset)))
+    }
+
+    /// Maps a single value.
+    pub fn get(&self, value: i64) -> i64 {
+        value.saturating_add(self.offset_at(value))
+    }
+
+    /// Maps all values of an interval, splitting it at the boundaries of the entries.
+    pub fn map_interval(&self, interval: Interval) -> IntervalSet {
+        let mut set = IntervalSet::new();
+
+        if interval.is_empty() {
+            return set;
+        }
+
+        let first = self
+            .entries
+            .partition_point(|(s, _)| s.end <= interval.start);
+        let mut cursor = interval.start;
+
+        for (source, offset) in self.entries[first..]
+            .iter()
+            .take_while(|(s, _)| s.start < interval.end)
+        {
+            // values in between entries are unchanged.
+            set.insert(Interval::new(cursor, source.start));
+
+            let overlap = source.intersection(&interval);
+            set.insert(overlap.shift(*offset));
+            cursor = overlap.end;
+        }
+
+        set.insert(Interval::new(cursor, interval.end));
+        set
+    }
+
+    /// Maps all values of a set.
+    pub fn map_set(&self, set: &IntervalSet) -> IntervalSet {
+        set.iter()
+            .flat_map(|interval| self.map_interval(interval).intervals)
+            .collect()
+    }
+
+    /// Returns the map that applies this map first and `then` second.
+    pub fn compose(&self, then: &RangeMap) -> RangeMap {
+        // the composed offset can only change where the offset of this map changes,
+        // or where this map hits a value at which the offset of `then` changes.
+        let mut breakpoints: Vec<i64> = self
+            .entries
+            .iter()
+            .flat_map(|(source, _)| [source.start, source.end])
+            .collect();
+
+        for value in then.entries.iter().flat_map(|(s, _)| [s.start, s.end]) {
+            if self.offset_at(value) == 0 {
+                breakpoints.push(value);
+            }
+
+            breakpoints.extend(
+                self.entries
+                    .iter()
+                    .map(|&(_, offset)| value - offset)
+                    .filter(|&preimage| {
+                        self.entries
+                            .iter()
+                            .any(|&(s, o)| s.contains(preimage) && preimage + o == value)
+                    }),
+            );
+        }
+
+        breakpoints.sort_unstable();
+        breakpoints.dedup();
+
+        // outside of all breakpoints both maps are the identity.
+        let mut composed = RangeMap::new();
+
+        for window in breakpoints.windows(2) {
+            let (start, end) = (window[0], window[1]);
+            let offset = self.offset_at(start) + then.offset_at(self.get(start));
+            composed.push_sorted(Interval::new(start, end), offset);
+        }
+
+        composed
+    }
+
+    /// Returns the map that undoes this map, or [`None`] if two values are mapped to the same value.
+    pub fn inverse(&self) -> Option<RangeMap> {
+        let sources: IntervalSet = self.entries.iter().map(|&(s, _)| s).collect();
+        let destinations: Vec<Interval> = self.entries().map(|(_, d)| d).collect();
+        let covered: IntervalSet = destinations.iter().copied().collect();
+
+        // every moved value must land on a moved value, otherwise it collides with an unchanged one.
+        if covered != sources || covered.len() != sources.len() {
+            return None;
+        }
+
+        let mut inverse: Vec<(Interval, i64)> = self
+            .entries
+            .iter()
+            .map(|&(source, offset)| (source.shift(offset), -offset))
+            .collect();
+        inverse.sort_unstable();
+
+        Some(RangeMap { entries: inverse })
+    }
+
+    fn offset_at(&self, value: i64) -> i64 {
+        let idx = self.entries.partition_point(|(s, _)| s.end <= value);
+
+        self.entries
+            .get(idx)
+            .filter(|(s, _)| s.contains(value))
+            .map_or(0, |&(_, offset)| offset)
+    }
+
+    /// Appends an entry that doesn't start before the last one, merging them if they touch and share an offset.
+    fn push_sorted(&mut self, source: Interval, offset: i64) {
+        if source.is_empty() || offset == 0 {
+            return;
+        }
+
+        match self.entries.last_mut() {
+            Some((last, last_offset)) if last.end == source.start && *last_offset == offset => {
+                last.end = source.end;
+            }
+            _ => self.entries.push((source, offset)),
+        }
+    }
+}
+
+/// An error which can be returned when inserting an entry into a [`RangeMap`].
+#[derive(Debug)]
+pub struct OverlapError {
+    /// The source interval of the entry that is already in the map.
+    pub existing: Interval,
+    pub source: Interval,
+}
+
+impl Error for OverlapError {}
+
+impl Display for OverlapError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "source interval {} overlaps the existing source interval {}",
+            self.source, self.existing
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::{Interval, IntervalSet, RangeMap};
+
+    /// Values that generated sets and maps are checked on, wide enough to include all shifted values.
+    const UNIVERSE: std::ops::Range<i64> = -200..200;
+
+    /// A small xorshift generator, so the property tests are reproducible without extra dependencies.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn range(&mut self, range: std::ops::Range<i64>) -> i64 {
+            range.start + (self.next() % (range.end - range.start) as u64) as i64
+        }
+
+        fn interval(&mut self) -> Interval {
+            let start = self.range(-50..50);
+            Interval::from_len(start, self.range(-2..20))
+        }
+
+        fn set(&mut self) -> IntervalSet {
+            let len = self.range(0..6);
+            (0..len).map(|_| self.interval()).collect()
+        }
+
+        fn map(&mut self) -> RangeMap {
+            let mut map = RangeMap::new();
+            for _ in 0..self.range(0..6) {
+                let source = self.interval();
+                // overlapping entries are rejected, which is fine for random maps.
+                let _ = map.insert(source, self.range(-60..60));
+            }
+            map
+        }
+    }
+
+    fn values(set: &IntervalSet) -> BTreeSet<i64> {
+        UNIVERSE.filter(|&v| set.contains(v)).collect()
+    }
+
+    fn assert_normalized(set: &IntervalSet) {
+        for i in set.intervals() {
+            assert!(!i.is_empty(), "{set:?} contains an empty interval");
+        }
+        for pair in set.intervals().windows(2) {
+            assert!(pair[0].end < pair[1].start, "{set:?} is not coalesced");
+        }
+    }
+
+    #[test]
+    fn interval_basics() {
+        let interval = Interval::new(3, 7);
+
+        assert_eq!(interval.len(), 4);
+        assert!(interval.contains(3) && !interval.contains(7));
+        assert!(Interval::new(5, 5).is_empty());
+        assert_eq!(Interval::new(5, 2).len(), 0);
+        assert_eq!(
+            interval.intersection(&Interval::new(5, 10)),
+            Interval::new(5, 7)
+        );
+        assert!(!interval.overlaps(&Interval::new(7, 10)));
+        assert_eq!(interval.shift(-3), Interval::from_len(0, 4));
+        assert_eq!(Interval::from(1..4), Interval::new(1, 4));
+        assert_eq!(interval.to_string(), "[3, 7)");
+    }
+
+    #[test]
+    fn saturates_at_the_limits() {
+        let near_max = Interval::from_len(i64::MAX - 1, 10);
+
+        assert_eq!(near_max, Interval::new(i64::MAX - 1, i64::MAX));
+        assert_eq!(near_max.len(), 1);
+        assert_eq!(near_max.shift(5), Interval::new(i64::MAX, i64::MAX));
+        assert!(near_max.shift(5).is_empty());
+        assert_eq!(Interval::new(i64::MIN, i64::MAX).len(), i64::MAX);
+        assert_eq!(
+            Interval::new(i64::MIN + 1, 0).shift(-3),
+            Interval::new(i64::MIN, -3)
+        );
+    }
+
+    #[test]
+    fn set_coalesces_intervals() {
+        let mut set = IntervalSet::new();
+        set.insert(Interval::new(0, 3));
+        set.insert(Interval::new(5, 8));
+        set.insert(Interval::new(3, 5));
+        set.insert(Interval::new(10, 10));
+
+        assert_eq!(set.intervals(), &[Interval::new(0, 8)]);
+        assert_eq!(set.len(), 8);
+        assert_eq!((set.min(), set.max()), (Some(0), Some(7)));
+    }
+
+    #[test]
+    fn set_operations_match_a_model() {
+        let mut rng = Rng(0x2023_1205);
+
+        for _ in 0..2000 {
+            let (a, b) = (rng.set(), rng.set());
+            let (va, vb) = (values(&a), values(&b));
+
+            let union = a.union(&b);
+            let intersection = a.intersection(&b);
+            let difference = a.difference(&b);
+
+            for set in [&a, &union, &intersection, &difference] {
+                assert_normalized(set);
+            }
+
+            assert_eq!(values(&union), &va | &vb);
+            assert_eq!(values(&intersection), &va & &vb);
+            assert_eq!(values(&difference), &va - &vb);
+            assert_eq!(a.len(), va.len() as i64);
+
+            let mut extended = a.clone();
+            extended.extend(b.iter());
+            assert_eq!(extended, union);
+        }
+    }
+
+    #[test]
+    fn map_rejects_overlapping_sources() {
+        let mut map = RangeMap::new();
+        map.insert(Interval::new(0, 10), 100).unwrap();
+
+        assert!(map.insert(Interval::new(9, 12), 0).is_err());
+        assert!(map.insert(Interval::new(10, 12), 0).is_ok());
+        assert_eq!(map.get(9), 109);
+        assert_eq!(map.get(11), 1);
+        assert_eq!(map.get(12), 12);
+    }
+
+    #[test]
+    fn map_intervals_match_a_model() {
+        let mut rng = Rng(0x5eed);
+
+        for _ in 0..2000 {
+            let map = rng.map();
+            let set = rng.set();
+
+            let mapped = map.map_set(&set);
+            assert_normalized(&mapped);
+
+            let expected: BTreeSet<i64> = values(&set).into_iter().map(|v| map.get(v)).collect();
+            assert_eq!(values(&mapped), expected);
+        }
+    }
+
+    #[test]
+    fn composition_matches_a_model() {
+        let mut rng = Rng(0xc0ffee);
+
+        for _ in 0..2000 {
+            let (first, second) = (rng.map(), rng.map());
+            let composed = first.compose(&second);
+
+            for v in UNIVERSE {
+                assert_eq!(
+                    composed.get(v),
+                    second.get(first.get(v)),
+                    "{first:?} {second:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn inverse_undoes_bijective_maps() {
+        let mut rng = Rng(0xabcdef);
+
+        for _ in 0..2000 {
+            // swapping two intervals of the same length is a bijection.
+            let len = rng.range(1..10);
+            let a = Interval::from_len(rng.range(-50..-10), len);
+            let b = Interval::from_len(rng.range(0..50), len);
+
+            let mut map = RangeMap::new();
+            map.insert(a, b.start).unwrap();
+            map.insert(b, a.start).unwrap();
+
+            let inverse = map.inverse().unwrap();
+
+            for v in UNIVERSE {
+                assert_eq!(inverse.get(map.get(v)), v);
+            }
+        }
+    }
+
+    #[test]
+    fn inverse_matches_a_model() {
+        let mut rng = Rng(0xfeed);
+
+        for _ in 0..2000 {
+            let map = rng.map();
+            let images: BTreeSet<i64> = UNIVERSE.map(|v| map.get(v)).collect();
+            let is_bijective =
+                images.len() == UNIVERSE.count() && images.iter().all(|v| UNIVERSE.contains(v));
+
+            match map.inverse() {
+                Some(inverse) => {
+                    assert!(is_bijective, "{map:?}");
+                    for v in UNIVERSE {
+                        assert_eq!(inverse.get(map.get(v)), v);
+                    }
+                }
+                None => assert!(!is_bijective, "{map:?}"),
+            }
+        }
+    }
+
+    /// The example of the module documentation, doctests are disabled for this crate.
+    #[test]
+    fn module_example() {
+        let mut map = RangeMap::new();
+        map.insert(Interval::new(98, 100), 50).unwrap();
+        map.insert(Interval::new(50, 98), 52).unwrap();
+
+        let seeds: IntervalSet = [Interval::from_len(79, 14), Interval::from_len(55, 13)]
+            .into_iter()
+            .collect();
+        assert_eq!(map.map_set(&seeds).min(), Some(57));
+    }
+}
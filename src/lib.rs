@@ -1,4 +1,9 @@
+mod date;
 mod day;
+pub mod grid;
+pub mod interval;
+pub mod parse;
 pub mod template;
 
+pub use date::*;
 pub use day::*;
@@ -1,55 +1,127 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve};
+use advent_of_code::template::commands::{
+    all, compare, download, read, scaffold, solve, verify, watch,
+};
+use advent_of_code::template::registry::Registry;
 use args::{parse, AppArguments};
 
+/// Solutions in `src/bin`, collected by the build script.
+mod solutions {
+    include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+}
+
 mod args {
     use std::process;
+    use std::time::Duration;
 
-    use advent_of_code::Day;
+    use advent_of_code::template::bench_history::Baseline;
+    use advent_of_code::template::day_selection::DaySelection;
+    use advent_of_code::template::input::InputSource;
+    use advent_of_code::template::output::OutputFormat;
+    use advent_of_code::template::timeout::parse_duration;
+    use advent_of_code::{AocDate, Day, Year};
 
     pub enum AppArguments {
+        Compare {
+            year: Year,
+            baseline: Baseline,
+            threshold: f64,
+            days: DaySelection,
+        },
         Download {
-            day: Day,
+            date: AocDate,
         },
         Read {
-            day: Day,
+            date: AocDate,
         },
         Scaffold {
-            day: Day,
+            date: AocDate,
         },
         Solve {
-            day: Day,
-            release: bool,
+            date: AocDate,
+            input: InputSource,
             time: bool,
             submit: Option<u8>,
+            format: OutputFormat,
+            timeout: Option<Duration>,
         },
         All {
+            year: Year,
             release: bool,
             time: bool,
+            format: OutputFormat,
+            timeout: Option<Duration>,
+            jobs: usize,
+            days: DaySelection,
+        },
+        Verify {
+            year: Year,
+            record: bool,
+            days: DaySelection,
+        },
+        Watch {
+            date: AocDate,
+            test: bool,
         },
     }
 
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
-        let app_args = match args.subcommand()?.as_deref() {
+        let subcommand = args.subcommand()?;
+        let year = parse_year(&mut args)?;
+        let date = |day: Day| AocDate::new(year, day);
+
+        let app_args = match subcommand.as_deref() {
             Some("all") => AppArguments::All {
+                year,
                 release: args.contains("--release"),
                 time: args.contains("--time"),
+                format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                timeout: args.opt_value_from_fn("--timeout", parse_duration)?,
+                jobs: args.opt_value_from_str("--jobs")?.unwrap_or(1),
+                days: args.opt_value_from_str("--days")?.unwrap_or_default(),
+            },
+            Some("compare") => AppArguments::Compare {
+                year,
+                baseline: args
+                    .opt_value_from_str("--baseline")?
+                    .unwrap_or(Baseline::Previous),
+                threshold: args.opt_value_from_str("--threshold")?.unwrap_or(10.0),
+                days: args.opt_value_from_str("--days")?.unwrap_or_default(),
             },
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                date: date(args.free_from_str()?),
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                date: date(args.free_from_str()?),
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+                date: date(args.free_from_str()?),
             },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                time: args.contains("--time"),
+            Some("solve") => {
+                // solutions run inside this binary, `--release` is only accepted for compatibility.
+                args.contains("--release");
+
+                if args.contains("--watch") {
+                    AppArguments::Watch {
+                        date: date(args.free_from_str()?),
+                        test: args.contains("--test"),
+                    }
+                } else {
+                    AppArguments::Solve {
+                        date: date(args.free_from_str()?),
+                        input: InputSource::from_args(&mut args)?,
+                        submit: args.opt_value_from_str("--submit")?,
+                        time: args.contains("--time"),
+                        format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                        timeout: args.opt_value_from_fn("--timeout", parse_duration)?,
+                    }
+                }
+            }
+            Some("verify") => AppArguments::Verify {
+                year,
+                record: args.contains("--record"),
+                days: args.opt_value_from_str("--days")?.unwrap_or_default(),
             },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
@@ -68,25 +140,58 @@ mod args {
 
         Ok(app_args)
     }
+
+    /// Reads the year from `--year`, falling back to the `AOC_YEAR` environment variable.
+    fn parse_year(args: &mut pico_args::Arguments) -> Result<Year, Box<dyn std::error::Error>> {
+        match args.opt_value_from_str("--year")? {
+            Some(year) => Ok(year),
+            None => Year::from_env().ok_or_else(|| {
+                "no year specified. Pass `--year <year>` or set AOC_YEAR in \".cargo/config.toml\"."
+                    .into()
+            }),
+        }
+    }
 }
 
 fn main() {
+    let registry = Registry::new(solutions::SOLUTIONS);
+
     match parse() {
         Err(err) => {
             eprintln!("Error: {err}");
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release, time } => all::handle(release, time),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold { day } => scaffold::handle(day),
-            AppArguments::Solve {
-                day,
+            AppArguments::All {
+                year,
                 release,
                 time,
+                format,
+                timeout,
+                jobs,
+                days,
+            } => all::handle(&registry, year, release, time, format, timeout, jobs, days),
+            AppArguments::Compare {
+                year,
+                baseline,
+                threshold,
+                days,
+            } => compare::handle(&registry, year, baseline, threshold, days),
+            AppArguments::Download { date } => download::handle(date),
+            AppArguments::Read { date } => read::handle(date),
+            AppArguments::Scaffold { date } => scaffold::handle(date),
+            AppArguments::Solve {
+                date,
+                input,
+                time,
                 submit,
-            } => solve::handle(day, release, time, submit),
+                format,
+                timeout,
+            } => solve::handle(&registry, date, &input, time, submit, format, timeout),
+            AppArguments::Verify { year, record, days } => {
+                verify::handle(&registry, year, record, days);
+            }
+            AppArguments::Watch { date, test } => watch::handle(date, test),
         },
     };
 }
@@ -0,0 +1,438 @@
+//! Small composable parsers for puzzle input formats.
+//!
+//! A parser consumes the start of its input and returns the parsed value with the remaining input,
+//! or a [`Failure`] that remembers where it failed. [`parse_all`] runs a parser on a whole input
+//! and turns failures into [`ParseError`]s with line and column.
+//!
+//! Repetitions like [`many`] stop at the first item they can't parse and leave it to the next parser,
+//! while [`separated`], [`lines`] and [`sections`] report a failing item once its separator was parsed.
+use std::any::type_name;
+use std::str::FromStr;
+
+use super::{is_word_char, ParseError};
+
+/// The parsed value and the remaining input, or the reason the parser failed.
+pub type PResult<'a, T> = Result<(T, &'a str), Failure<'a>>;
+
+/// The reason a parser failed, before it's located in the whole input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure<'a> {
+    /// The remaining input at the position of the failure.
+    pub at: &'a str,
+    /// A description of what was expected, e.g. `a number`.
+    pub expected: String,
+}
+
+impl<'a> Failure<'a> {
+    pub fn new(at: &'a str, expected: impl Into<String>) -> Self {
+        Self {
+            at,
+            expected: expected.into(),
+        }
+    }
+}
+
+/// A parser that produces a `T`. Closures with the signature `Fn(&str) -> PResult<T>` are parsers too.
+pub trait Parser<'a, T> {
+    fn parse(&self, input: &'a str) -> PResult<'a, T>;
+
+    /// Converts the parsed value.
+    fn map<U>(self, f: impl Fn(T) -> U) -> impl Parser<'a, U>
+    where
+        Self: Sized,
+    {
+        move |input: &'a str| self.parse(input).map(|(value, rest)| (f(value), rest))
+    }
+
+    /// Converts the parsed value with a conversion that can fail, e.g. a validation.
+    fn try_map<U>(self, f: impl Fn(T) -> Result<U, Failure<'a>>) -> impl Parser<'a, U>
+    where
+        Self: Sized,
+    {
+        move |input: &'a str| {
+            let (value, rest) = self.parse(input)?;
+            Ok((f(value)?, rest))
+        }
+    }
+
+    /// Parses `next` after this parser and returns both values.
+    fn then<U>(self, next: impl Parser<'a, U>) -> impl Parser<'a, (T, U)>
+    where
+        Self: Sized,
+    {
+        move |input: &'a str| {
+            let (first, rest) = self.parse(input)?;
+            let (second, rest) = next.parse(rest)?;
+            Ok(((first, second), rest))
+        }
+    }
+
+    /// Parses `next` after this parser and only returns the value of this parser.
+    fn then_ignore<U>(self, next: impl Parser<'a, U>) -> impl Parser<'a, T>
+    where
+        Self: Sized,
+    {
+        self.then(next).map(|(value, _)| value)
+    }
+
+    /// Parses `next` after this parser and only returns the value of `next`.
+    fn ignore_then<U>(self, next: impl Parser<'a, U>) -> impl Parser<'a, U>
+    where
+        Self: Sized,
+    {
+        self.then(next).map(|(_, value)| value)
+    }
+
+    /// Also returns the part of the input that this parser consumed, e.g. to point errors at it.
+    fn spanned(self) -> impl Parser<'a, (T, &'a str)>
+    where
+        Self: Sized,
+    {
+        move |input: &'a str| {
+            let (value, rest) = self.parse(input)?;
+            Ok(((value, &input[..input.len() - rest.len()]), rest))
+        }
+    }
+}
+
+impl<'a, T, F> Parser<'a, T> for F
+where
+    F: Fn(&'a str) -> PResult<'a, T>,
+{
+    fn parse(&self, input: &'a str) -> PResult<'a, T> {
+        self(input)
+    }
+}
+
+/// Runs a parser on a whole input, only whitespace may be left over.
+pub fn parse_all<'a, T>(parser: impl Parser<'a, T>, input: &'a str) -> Result<T, ParseError> {
+    let (value, rest) = parser
+        .parse(input)
+        .map_err(|failure| ParseError::at(input, failure.at, failure.expected))?;
+
+    let rest = rest.trim_start();
+
+    if !rest.is_empty() {
+        return Err(ParseError::at(input, rest, "the end of the input"));
+    }
+
+    Ok(value)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A fixed piece of text.
+pub fn literal<'a>(expected: &'static str) -> impl Parser<'a, &'a str> {
+    move |input: &'a str| match input.strip_prefix(expected) {
+        Some(rest) => Ok((&input[..expected.len()], rest)),
+        None => Err(Failure::new(input, format!("`{expected}`"))),
+    }
+}
+
+/// Any number of spaces and tabs, but no line breaks.
+pub fn spaces<'a>() -> impl Parser<'a, &'a str> {
+    |input: &'a str| {
+        let rest = input.trim_start_matches([' ', '\t']);
+        Ok((&input[..input.len() - rest.len()], rest))
+    }
+}
+
+/// Skips spaces before a parser.
+pub fn ws<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    spaces().ignore_then(parser)
+}
+
+/// A word of letters, digits, `-` and `_`, e.g. a label like `seed-to-soil`.
+pub fn word<'a>() -> impl Parser<'a, &'a str> {
+    |input: &'a str| match input.find(|c| !is_word_char(c)).unwrap_or(input.len()) {
+        0 => Err(Failure::new(input, "a word")),
+        len => Ok(input.split_at(len)),
+    }
+}
+
+/// An integer, optionally negative. It can't be followed by a word character, so `3x` is not a number.
+pub fn number<'a, T: FromStr>() -> impl Parser<'a, T> {
+    |input: &'a str| {
+        let sign = usize::from(input.starts_with('-'));
+        let digits = input[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(input.len() - sign);
+
+        let (token, rest) = input.split_at(sign + digits);
+
+        if digits == 0 || rest.starts_with(is_word_char) {
+            return Err(Failure::new(input, "a number"));
+        }
+
+        token
+            .parse()
+            .map(|number| (number, rest))
+            .map_err(|_| Failure::new(input, format!("a number that fits `{}`", type_name::<T>())))
+    }
+}
+
+/// Numbers separated by spaces, possibly none.
+pub fn numbers<'a, T: FromStr>() -> impl Parser<'a, Vec<T>> {
+    many(ws(number()))
+}
+
+/// One of several keywords, returns the value that belongs to the keyword.
+/// Keywords that end with a word character must end a word in the input, too.
+pub fn one_of<'a, T: Copy>(choices: &'static [(&'static str, T)]) -> impl Parser<'a, T> {
+    move |input: &'a str| {
+        choices
+            .iter()
+            .find_map(|&(keyword, value)| {
+                let rest = input.strip_prefix(keyword)?;
+                let splits_word = keyword.ends_with(is_word_char) && rest.starts_with(is_word_char);
+                (!splits_word).then_some((value, rest))
+            })
+            .ok_or_else(|| Failure::new(input, describe_choices(choices)))
+    }
+}
+
+/// Describes keywords like "`red`, `green` or `blue`".
+fn describe_choices<T>(choices: &[(&str, T)]) -> String {
+    let keywords: Vec<String> = choices.iter().map(|(k, _)| format!("`{k}`")).collect();
+
+    match keywords.split_last() {
+        Some((last, [])) => last.clone(),
+        Some((last, rest)) => format!("{} or {last}", rest.join(", ")),
+        None => "nothing".into(),
+    }
+}
+
+/// A label followed by a value, like `Game 12`.
+pub fn labelled<'a, T>(label: &'static str, value: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    literal(label).ignore_then(ws(value))
+}
+
+/// A key and a value separated by a colon, like `Time: 7 15 30`.
+pub fn key_value<'a, K, V>(
+    key: impl Parser<'a, K>,
+    value: impl Parser<'a, V>,
+) -> impl Parser<'a, (K, V)> {
+    key.then_ignore(ws(literal(":"))).then(ws(value))
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Any number of repetitions of a parser, up to the first input it can't parse.
+pub fn many<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    move |mut input: &'a str| {
+        let mut items = Vec::new();
+
+        while let Ok((item, rest)) = parser.parse(input) {
+            items.push(item);
+
+            // a parser that consumes nothing would match forever.
+            if rest.len() == input.len() {
+                break;
+            }
+
+            input = rest;
+        }
+
+        Ok((items, input))
+    }
+}
+
+/// At least one item, with separators in between. Once a separator was parsed, an item must follow it.
+pub fn separated<'a, T, S>(
+    item: impl Parser<'a, T>,
+    separator: impl Parser<'a, S>,
+) -> impl Parser<'a, Vec<T>> {
+    move |input: &'a str| {
+        let (first, mut rest) = item.parse(input)?;
+        let mut items = vec![first];
+
+        while let Ok((_, after_separator)) = separator.parse(rest) {
+            let (next, after_item) = item.parse(after_separator)?;
+            items.push(next);
+            rest = after_item;
+        }
+
+        Ok((items, rest))
+    }
+}
+
+/// The end of a line, i.e. a line break or the end of the input. Trailing spaces are skipped.
+pub fn end_of_line<'a>() -> impl Parser<'a, ()> {
+    |input: &'a str| {
+        let rest = input.trim_start_matches([' ', '\t']);
+
+        if rest.is_empty() {
+            return Ok(((), rest));
+        }
+
+        rest.strip_prefix("\r\n")
+            .or_else(|| rest.strip_prefix('\n'))
+            .map(|rest| ((), rest))
+            .ok_or_else(|| Failure::new(rest, "the end of the line"))
+    }
+}
+
+/// A parser that has to cover a whole line.
+pub fn line<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    parser.then_ignore(end_of_line())
+}
+
+/// Lines up to the end of the input or the next blank line, each covered by `parser`.
+pub fn lines<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    let parser = line(parser);
+
+    move |mut input: &'a str| {
+        let mut items = Vec::new();
+
+        while !input.is_empty() && !input.starts_with(['\n', '\r']) {
+            let (item, rest) = parser.parse(input)?;
+            items.push(item);
+            input = rest;
+        }
+
+        Ok((items, input))
+    }
+}
+
+/// Sections separated by blank lines up to the end of the input, each parsed by `parser`.
+pub fn sections<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    move |mut input: &'a str| {
+        let mut items = Vec::new();
+
+        loop {
+            input = input.trim_start_matches(['\n', '\r']);
+
+            if input.is_empty() {
+                return Ok((items, input));
+            }
+
+            let (item, rest) = parser.parse(input)?;
+
+            if rest.len() == input.len() {
+                return Err(Failure::new(input, "a section"));
+            }
+
+            items.push(item);
+            input = rest;
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Color {
+        Red,
+        Green,
+    }
+
+    const COLORS: &[(&str, Color)] = &[("red", Color::Red), ("green", Color::Green)];
+
+    #[test]
+    fn parses_literals_and_words() {
+        assert_eq!(literal("Game").parse("Game 1"), Ok(("Game", " 1")));
+        assert_eq!(
+            literal("Game").parse("Card 1"),
+            Err(Failure::new("Card 1", "`Game`"))
+        );
+        assert_eq!(
+            word().parse("seed-to-soil map:"),
+            Ok(("seed-to-soil", " map:"))
+        );
+        assert!(word().parse(" x").is_err());
+        assert_eq!(ws(word()).parse("  x"), Ok(("x", "")));
+    }
+
+    #[test]
+    fn parses_numbers() {
+        assert_eq!(number::<u32>().parse("42 x"), Ok((42, " x")));
+        assert_eq!(number::<i64>().parse("-7,"), Ok((-7, ",")));
+        assert!(number::<u32>().parse("-7").is_err());
+        assert!(number::<u32>().parse("3x").is_err());
+        assert!(number::<u32>().parse("-").is_err());
+
+        let overflow = number::<u8>().parse("300").unwrap_err();
+        assert_eq!(overflow.expected, "a number that fits `u8`");
+
+        assert_eq!(
+            numbers::<u32>().parse(" 1  2 3 |"),
+            Ok((vec![1, 2, 3], " |"))
+        );
+        assert_eq!(numbers::<u32>().parse("x"), Ok((vec![], "x")));
+    }
+
+    #[test]
+    fn parses_keywords() {
+        assert_eq!(one_of(COLORS).parse("green,"), Ok((Color::Green, ",")));
+        assert_eq!(
+            one_of(COLORS).parse("reddish").unwrap_err().expected,
+            "`red` or `green`"
+        );
+    }
+
+    #[test]
+    fn parses_labels_and_key_values() {
+        assert_eq!(
+            labelled("Game", number::<u32>()).parse("Game  12:"),
+            Ok((12, ":"))
+        );
+        assert_eq!(
+            key_value(word(), numbers::<u32>()).parse("Time:  7 15"),
+            Ok((("Time", vec![7, 15]), ""))
+        );
+    }
+
+    #[test]
+    fn commits_to_items_after_separators() {
+        let list = separated(ws(number::<u32>()), literal(","));
+
+        assert_eq!(list.parse("1, 2,3;"), Ok((vec![1, 2, 3], ";")));
+        assert_eq!(list.parse("1, x").unwrap_err().at, "x");
+        assert_eq!(list.parse("x").unwrap_err().at, "x");
+    }
+
+    #[test]
+    fn parses_lines_and_sections() {
+        let input = "1 2\n3\n\n4\n";
+        let parser = sections(lines(numbers::<u32>()));
+
+        assert_eq!(
+            parse_all(parser, input),
+            Ok(vec![vec![vec![1, 2], vec![3]], vec![vec![4]]])
+        );
+
+        let error = parse_all(lines(numbers::<u32>()), "1 2\n3 x\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.expected, "the end of the line");
+    }
+
+    #[test]
+    fn reports_leftover_input() {
+        let error = parse_all(number::<u32>(), "1\n 2").unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.expected, "the end of the input");
+    }
+
+    #[test]
+    fn maps_values_with_spans() {
+        let even = || {
+            number::<u32>().spanned().try_map(|(n, span)| {
+                if n % 2 == 0 {
+                    Ok(n / 2)
+                } else {
+                    Err(Failure::new(span, "an even number"))
+                }
+            })
+        };
+
+        assert_eq!(parse_all(even(), "42"), Ok(21));
+
+        let error = parse_all(even(), "43").unwrap_err();
+        assert_eq!((error.column, error.found.as_str()), (1, "43"));
+    }
+}
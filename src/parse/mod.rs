@@ -0,0 +1,257 @@
+//! Parsing puzzle inputs, with errors that point at the offending part of the input.
+//!
+//! Simple formats can be parsed line by line with [`parse_lines`]:
+//!
+//! ```
+//! # use advent_of_code::parse::{parse_lines, ParseError};
+//! let input = "1\n2\nthree\n";
+//!
+//! let error = parse_lines(input, |line| {
+//!     line.parse::<u32>()
+//!         .map_err(|_| ParseError::new(line, line, "a number"))
+//! })
+//! .unwrap_err();
+//!
+//! assert_eq!(error.to_string(), "line 3, column 1: expected a number, found `three`");
+//! ```
+//!
+//! Other formats can be declared with the parsers in [`combinators`], which are re-exported here:
+//!
+//! ```
+//! # use advent_of_code::parse::{key_value, labelled, lines, number, numbers, parse_all};
+//! let input = "Card 1: 41 48\nCard 2: 13 32\n";
+//! let card = key_value(labelled("Card", number::<u32>()), numbers::<u32>());
+//!
+//! let cards = parse_all(lines(card), input).unwrap();
+//! assert_eq!(cards[1], (2, vec![13, 32]));
+//! ```
+use std::error::Error;
+use std::fmt::Display;
+
+pub mod combinators;
+
+pub use combinators::*;
+
+/// An error which can be returned when an input doesn't have the expected format.
+/// Lines and columns count from 1, columns count characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    /// A description of what was expected at the position, e.g. `a number`.
+    pub expected: String,
+    /// The input that was found instead, empty at the end of a line.
+    pub found: String,
+}
+
+impl ParseError {
+    /// Creates an error for `part`, which must be a slice of `line` (like the results of `split_once` or `trim`),
+    /// so the column can be derived from it. The line number is set by [`ParseError::on_line`].
+    pub fn new(line: &str, part: &str, expected: impl Into<String>) -> Self {
+        let offset = (part.as_ptr() as usize)
+            .checked_sub(line.as_ptr() as usize)
+            .filter(|&offset| offset <= line.len() && line.is_char_boundary(offset))
+            .unwrap_or(0);
+
+        Self {
+            line: 1,
+            column: line[..offset].chars().count() + 1,
+            expected: expected.into(),
+            found: part.to_string(),
+        }
+    }
+
+    /// Creates an error at `at`, which must be a slice of `input`, e.g. the remaining input of a failed parser.
+    /// The line and column are derived from its position, the token found there is the run of word characters
+    /// (or the single character) it starts with.
+    pub fn at(input: &str, at: &str, expected: impl Into<String>) -> Self {
+        let offset = (at.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset <= input.len() && input.is_char_boundary(offset))
+            .unwrap_or(0);
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+
+        let rest = &input[offset..];
+        let token_len = match rest.chars().next() {
+            None | Some('\n' | '\r') => 0,
+            Some(c) if is_word_char(c) => rest.find(|c| !is_word_char(c)).unwrap_or(rest.len()),
+            Some(c) => c.len_utf8(),
+        };
+
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            expected: expected.into(),
+            found: rest[..token_len].to_string(),
+        }
+    }
+
+    /// Sets the line number of the error.
+    #[must_use]
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+
+    /// Renders the error with the offending line of `input` and a marker below the position.
+    #[must_use]
+    pub fn diagnostic(&self, input: &str) -> String {
+        let mut diagnostic = format!("error: {self}");
+
+        if let Some(line) = input.lines().nth(self.line.saturating_sub(1)) {
+            let number = self.line.to_string();
+            let padding = " ".repeat(number.len());
+            let indent = " ".repeat(self.column.saturating_sub(1));
+            let marker = "^".repeat(self.found.chars().count().max(1));
+
+            diagnostic.push_str(&format!(
+                "\n{padding} |\n{number} | {line}\n{padding} | {indent}{marker}"
+            ));
+        }
+
+        diagnostic
+    }
+}
+
+impl Error for ParseError {}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )?;
+
+        if self.found.is_empty() {
+            f.write_str(", found the end of the line")
+        } else {
+            write!(f, ", found `{}`", self.found)
+        }
+    }
+}
+
+/// Characters that form words like labels, colors or numbers.
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '-' || c == '_'
+}
+
+/// Parses every line of an input, stopping at the first line that fails.
+/// The errors of `parse` are relative to the line they occur in, this sets their line number.
+pub fn parse_lines<T>(
+    input: &str,
+    mut parse: impl FnMut(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| parse(line).map_err(|e| e.on_line(idx + 1)))
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_lines, ParseError};
+
+    fn parse_number(line: &str) -> Result<u32, ParseError> {
+        let (_, number) = line
+            .split_once(':')
+            .ok_or_else(|| ParseError::new(line, line, "`:`"))?;
+        let number = number.trim();
+
+        number
+            .parse()
+            .map_err(|_| ParseError::new(line, number, "a number"))
+    }
+
+    #[test]
+    fn locates_errors_in_lines() {
+        let error = parse_lines("a: 1\nb: 2\nc: x3\n", parse_number).unwrap_err();
+
+        assert_eq!((error.line, error.column), (3, 4));
+        assert_eq!(error.found, "x3");
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 4: expected a number, found `x3`"
+        );
+
+        assert_eq!(parse_lines("a: 1\nb: 2", parse_number), Ok(vec![1, 2]));
+    }
+
+    #[test]
+    fn locates_errors_in_inputs() {
+        let input = "a: 1\nb: 2\nc: x3, 4\n";
+        let error = ParseError::at(input, &input[13..], "a number");
+
+        assert_eq!((error.line, error.column), (3, 4));
+        assert_eq!(error.found, "x3");
+
+        let error = ParseError::at(input, &input[15..], "a number");
+        assert_eq!(error.found, ",");
+
+        let error = ParseError::at(input, &input[input.len()..], "a number");
+        assert_eq!((error.line, error.column), (4, 1));
+        assert_eq!(error.found, "");
+    }
+
+    #[test]
+    fn counts_columns_in_characters() {
+        let line = "ä: x";
+        let error = ParseError::new(line, &line[4..], "a number");
+
+        assert_eq!(error.column, 4);
+    }
+
+    #[test]
+    fn reports_the_end_of_a_line() {
+        let line = "a:";
+        let error = ParseError::new(line, &line[2..], "a number");
+
+        assert_eq!(error.column, 3);
+        assert!(error.to_string().ends_with("found the end of the line"));
+    }
+
+    #[test]
+    fn renders_diagnostics() {
+        let input = "a: 1\nb: 2\nc: x3\n";
+        let error = parse_lines(input, parse_number).unwrap_err();
+
+        assert_eq!(
+            error.diagnostic(input),
+            "error: line 3, column 4: expected a number, found `x3`\n  |\n3 | c: x3\n  |    ^^"
+        );
+    }
+
+    /// The line-by-line example of the module documentation, doctests are disabled for this crate.
+    #[test]
+    fn module_example_lines() {
+        let input = "1\n2\nthree\n";
+
+        let error = parse_lines(input, |line| {
+            line.parse::<u32>()
+                .map_err(|_| ParseError::new(line, line, "a number"))
+        })
+        .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 1: expected a number, found `three`"
+        );
+    }
+
+    /// The combinator example of the module documentation.
+    #[test]
+    fn module_example_combinators() {
+        use super::{key_value, labelled, lines, number, numbers, parse_all};
+
+        let input = "Card 1: 41 48\nCard 2: 13 32\n";
+        let card = key_value(labelled("Card", number::<u32>()), numbers::<u32>());
+
+        let cards = parse_all(lines(card), input).unwrap();
+        assert_eq!(cards[1], (2, vec![13, 32]));
+    }
+}
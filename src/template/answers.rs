@@ -0,0 +1,211 @@
+/// Module that stores accepted answers in `data/<year>/answers/<day>.toml`.
+/// The files use a small subset of TOML: comments and `part_one` / `part_two` keys with string or integer values.
+use std::{fmt::Write, fs, io, path::Path};
+
+use crate::{AocDate, Day};
+
+const KEYS: [&str; 2] = ["part_one", "part_two"];
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(msg) => write!(f, "{msg}"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+/// The accepted answers of a day.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+impl Answers {
+    /// Returns the accepted answer for a part.
+    #[must_use]
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_one.as_deref(),
+            2 => self.part_two.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Sets the accepted answer for a part.
+    pub fn set(&mut self, part: u8, answer: String) {
+        match part {
+            1 => self.part_one = Some(answer),
+            2 => self.part_two = Some(answer),
+            _ => {}
+        }
+    }
+}
+
+#[must_use]
+pub fn get_path(date: AocDate) -> String {
+    format!("data/{}/answers/{}.toml", date.year, date.day)
+}
+
+/// Reads the accepted answers of a day, returns [`None`] if no answers were recorded yet.
+pub fn read(date: AocDate) -> Result<Option<Answers>, Error> {
+    let path = get_path(date);
+
+    if !Path::new(&path).exists() {
+        return Ok(None);
+    }
+
+    parse(&fs::read_to_string(path)?).map(Some)
+}
+
+/// Writes the accepted answers of a day, replacing any previously recorded answers.
+pub fn write(date: AocDate, answers: &Answers) -> Result<(), Error> {
+    fs::create_dir_all(format!("data/{}/answers", date.year))?;
+    fs::write(get_path(date), serialize(date.day, answers))?;
+    Ok(())
+}
+
+fn parse(s: &str) -> Result<Answers, Error> {
+    let mut answers = Answers::default();
+
+    for (i, line) in s.lines().enumerate() {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| Error::Parser(format!("line {}: expected `key = value`.", i + 1)))?;
+
+        let value = parse_value(value.trim())
+            .ok_or_else(|| Error::Parser(format!("line {}: invalid value.", i + 1)))?;
+
+        match KEYS.iter().position(|&k| k == key.trim()) {
+            Some(idx) => answers.set(idx as u8 + 1, value),
+            None => {
+                return Err(Error::Parser(format!(
+                    "line {}: unknown key `{}`.",
+                    i + 1,
+                    key.trim()
+                )))
+            }
+        }
+    }
+
+    Ok(answers)
+}
+
+fn parse_value(value: &str) -> Option<String> {
+    if let Some(quoted) = value.strip_prefix('"') {
+        let mut unescaped = String::new();
+        let mut chars = quoted.chars();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '"' => {
+                    // only comments may follow the closing quote.
+                    let rest = chars.as_str().trim();
+                    return (rest.is_empty() || rest.starts_with('#')).then_some(unescaped);
+                }
+                '\\' => unescaped.push(match chars.next()? {
+                    'n' => '\n',
+                    't' => '\t',
+                    'r' => '\r',
+                    c @ ('"' | '\\') => c,
+                    _ => return None,
+                }),
+                c => unescaped.push(c),
+            }
+        }
+
+        return None;
+    }
+
+    let value = value.split('#').next()?.trim();
+
+    (!value.is_empty() && value.chars().all(|c| c.is_ascii_digit() || c == '-'))
+        .then(|| value.to_string())
+}
+
+fn serialize(day: Day, answers: &Answers) -> String {
+    let mut s = format!("# Accepted answers for day {day}.\n");
+
+    for (idx, key) in KEYS.iter().enumerate() {
+        if let Some(answer) = answers.get(idx as u8 + 1) {
+            let escaped = answer
+                .replace('\\', "\\\\")
+                .replace('"', "\\\"")
+                .replace('\n', "\\n")
+                .replace('\t', "\\t")
+                .replace('\r', "\\r");
+            let _ = writeln!(s, "{key} = \"{escaped}\"");
+        }
+    }
+
+    s
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse, serialize, Answers};
+    use crate::day;
+
+    #[test]
+    fn parses_answers() {
+        let answers =
+            parse("# comment\npart_one = \"142\"\n\npart_two = 281 # trailing\n").unwrap();
+        assert_eq!(answers.get(1), Some("142"));
+        assert_eq!(answers.get(2), Some("281"));
+    }
+
+    #[test]
+    fn parses_partial_answers() {
+        let answers = parse("part_two = \"ABC\"").unwrap();
+        assert_eq!(answers.get(1), None);
+        assert_eq!(answers.get(2), Some("ABC"));
+    }
+
+    #[test]
+    fn rejects_malformed_answers() {
+        assert!(parse("part_one").is_err());
+        assert!(parse("part_three = \"1\"").is_err());
+        assert!(parse("part_one = \"1").is_err());
+        assert!(parse("part_one = abc").is_err());
+    }
+
+    #[test]
+    fn roundtrips_answers() {
+        let answers = Answers {
+            part_one: Some("a \"quoted\"\nmulti-line \\ answer".into()),
+            part_two: None,
+        };
+        assert_eq!(parse(&serialize(day!(1), &answers)).unwrap(), answers);
+    }
+
+    #[test]
+    fn serializes_answers() {
+        let answers = Answers {
+            part_one: Some("142".into()),
+            part_two: Some("281".into()),
+        };
+        assert_eq!(
+            serialize(day!(1), &answers),
+            "# Accepted answers for day 01.\npart_one = \"142\"\npart_two = \"281\"\n"
+        );
+    }
+}
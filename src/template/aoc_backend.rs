@@ -0,0 +1,139 @@
+/// Selects how the template talks to the Advent of Code website.
+/// The native client is preferred, the "aoc-cli" command-line is used as a fallback if no session cookie is configured.
+use std::{env, fmt::Display, fs};
+
+use crate::template::aoc_cli::{self, AocCommandError};
+use crate::template::aoc_client::{AocClient, AocClientError, SubmissionResponse};
+use crate::AocDate;
+
+#[derive(Debug)]
+pub enum AocBackendError {
+    Client(AocClientError),
+    Cli(AocCommandError),
+    /// Neither a session cookie nor aoc-cli is available.
+    Unavailable,
+}
+
+impl Display for AocBackendError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocBackendError::Client(e) => write!(f, "{e}"),
+            AocBackendError::Cli(e) => write!(f, "{e}"),
+            AocBackendError::Unavailable => write!(
+                f,
+                "no session cookie found and aoc-cli is not installed. Set AOC_SESSION or create \"~/.adventofcode.session\"."
+            ),
+        }
+    }
+}
+
+impl From<AocClientError> for AocBackendError {
+    fn from(e: AocClientError) -> Self {
+        AocBackendError::Client(e)
+    }
+}
+
+impl From<AocCommandError> for AocBackendError {
+    fn from(e: AocCommandError) -> Self {
+        AocBackendError::Cli(e)
+    }
+}
+
+pub enum AocBackend {
+    Native(AocClient),
+    Cli,
+}
+
+impl AocBackend {
+    /// Picks a backend. `AOC_BACKEND` can be set to `native` or `cli` to force one,
+    /// otherwise the native client is used if a session cookie is configured and aoc-cli if not.
+    pub fn detect() -> Result<Self, AocBackendError> {
+        match env::var("AOC_BACKEND").as_deref() {
+            Ok("cli") => {
+                aoc_cli::check()?;
+                Ok(AocBackend::Cli)
+            }
+            Ok("native") => Ok(AocBackend::Native(AocClient::from_env()?)),
+            _ => match AocClient::from_env() {
+                Ok(client) => Ok(AocBackend::Native(client)),
+                Err(AocClientError::MissingSession) => aoc_cli::check()
+                    .map(|()| AocBackend::Cli)
+                    .map_err(|_| AocBackendError::Unavailable),
+                Err(e) => Err(e.into()),
+            },
+        }
+    }
+
+    /// Downloads the input and the puzzle description of a day to the data folder.
+    pub fn download(&self, date: AocDate) -> Result<(), AocBackendError> {
+        match self {
+            AocBackend::Native(client) => {
+                let input_path = aoc_cli::get_input_path(date);
+                let puzzle_path = aoc_cli::get_puzzle_path(date);
+
+                let input = client.input(date)?;
+                let puzzle = client.puzzle(date)?;
+
+                fs::create_dir_all(format!("data/{}/inputs", date.year))
+                    .and_then(|()| fs::create_dir_all(format!("data/{}/puzzles", date.year)))
+                    .map_err(AocClientError::from)?;
+                fs::write(&input_path, input).map_err(AocClientError::from)?;
+                fs::write(&puzzle_path, puzzle).map_err(AocClientError::from)?;
+
+                println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+                println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+            }
+            AocBackend::Cli => {
+                aoc_cli::download(date)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Prints the puzzle description of a day.
+    pub fn read(&self, date: AocDate) -> Result<(), AocBackendError> {
+        match self {
+            AocBackend::Native(client) => {
+                let puzzle = client.puzzle(date)?;
+                fs::create_dir_all(format!("data/{}/puzzles", date.year))
+                    .and_then(|()| fs::write(aoc_cli::get_puzzle_path(date), &puzzle))
+                    .map_err(AocClientError::from)?;
+                println!("{puzzle}");
+            }
+            AocBackend::Cli => {
+                aoc_cli::read(date)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Submits an answer for a part of a day.
+    /// aoc-cli prints the response itself, its output is returned as the message.
+    pub fn submit(
+        &self,
+        date: AocDate,
+        part: u8,
+        answer: &str,
+    ) -> Result<SubmissionResponse, AocBackendError> {
+        match self {
+            AocBackend::Native(client) => Ok(client.submit(date, part, answer)?),
+            AocBackend::Cli => {
+                let output = aoc_cli::submit(date, part, answer)?;
+                Ok(SubmissionResponse {
+                    message: String::from_utf8_lossy(&output.stdout).into_owned(),
+                })
+            }
+        }
+    }
+}
+
+impl Display for AocBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocBackend::Native(_) => write!(f, "the native client"),
+            AocBackend::Cli => write!(f, "aoc-cli"),
+        }
+    }
+}
@@ -4,7 +4,7 @@ use std::{
     process::{Command, Output, Stdio},
 };
 
-use crate::Day;
+use crate::AocDate;
 
 #[derive(Debug)]
 pub enum AocCommandError {
@@ -35,8 +35,8 @@ pub fn check() -> Result<(), AocCommandError> {
     Ok(())
 }
 
-pub fn read(day: Day) -> Result<Output, AocCommandError> {
-    let puzzle_path = get_puzzle_path(day);
+pub fn read(date: AocDate) -> Result<Output, AocCommandError> {
+    let puzzle_path = get_puzzle_path(date);
 
     let args = build_args(
         "read",
@@ -45,15 +45,15 @@ pub fn read(day: Day) -> Result<Output, AocCommandError> {
             "--puzzle-file".into(),
             puzzle_path,
         ],
-        day,
+        date,
     );
 
     call_aoc_cli(&args)
 }
 
-pub fn download(day: Day) -> Result<Output, AocCommandError> {
-    let input_path = get_input_path(day);
-    let puzzle_path = get_puzzle_path(day);
+pub fn download(date: AocDate) -> Result<Output, AocCommandError> {
+    let input_path = get_input_path(date);
+    let puzzle_path = get_puzzle_path(date);
 
     let args = build_args(
         "download",
@@ -64,7 +64,7 @@ pub fn download(day: Day) -> Result<Output, AocCommandError> {
             "--puzzle-file".into(),
             puzzle_path.to_string(),
         ],
-        day,
+        date,
     );
 
     let output = call_aoc_cli(&args)?;
@@ -74,38 +74,33 @@ pub fn download(day: Day) -> Result<Output, AocCommandError> {
     Ok(output)
 }
 
-pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandError> {
+/// Submits an answer. The output of aoc-cli is captured so the response can be interpreted, and echoed afterwards.
+pub fn submit(date: AocDate, part: u8, result: &str) -> Result<Output, AocCommandError> {
     // workaround: the argument order is inverted for submit.
-    let mut args = build_args("submit", &[], day);
+    let mut args = build_args("submit", &[], date);
     args.push(part.to_string());
     args.push(result.to_string());
-    call_aoc_cli(&args)
-}
-
-fn get_input_path(day: Day) -> String {
-    format!("data/inputs/{day}.txt")
+    call_aoc_cli_captured(&args)
 }
 
-fn get_puzzle_path(day: Day) -> String {
-    format!("data/puzzles/{day}.md")
+pub(crate) fn get_input_path(date: AocDate) -> String {
+    format!("data/{}/inputs/{}.txt", date.year, date.day)
 }
 
-fn get_year() -> Option<u16> {
-    match std::env::var("AOC_YEAR") {
-        Ok(x) => x.parse().ok().or(None),
-        Err(_) => None,
-    }
+pub(crate) fn get_puzzle_path(date: AocDate) -> String {
+    format!("data/{}/puzzles/{}.md", date.year, date.day)
 }
 
-fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
+fn build_args(command: &str, args: &[String], date: AocDate) -> Vec<String> {
     let mut cmd_args = args.to_vec();
 
-    if let Some(year) = get_year() {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
-    }
-
-    cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
+    cmd_args.append(&mut vec![
+        "--year".into(),
+        date.year.to_string(),
+        "--day".into(),
+        date.day.to_string(),
+        command.into(),
+    ]);
 
     cmd_args
 }
@@ -119,6 +114,22 @@ fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
         .output()
         .map_err(|_| AocCommandError::CommandNotCallable)?;
 
+    check_status(output)
+}
+
+/// Calls aoc-cli with its stdout captured, the caller prints it.
+fn call_aoc_cli_captured(args: &[String]) -> Result<Output, AocCommandError> {
+    let output = Command::new("aoc")
+        .args(args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|_| AocCommandError::CommandNotCallable)?;
+
+    check_status(output)
+}
+
+fn check_status(output: Output) -> Result<Output, AocCommandError> {
     if output.status.success() {
         Ok(output)
     } else {
@@ -0,0 +1,353 @@
+/// Native client for the Advent of Code website.
+/// Reads the session cookie from the environment or from the same file as aoc-cli, so both can be used interchangeably.
+use std::{env, fmt::Display, fs, io, path::PathBuf, time::Duration};
+
+use crate::AocDate;
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = concat!(
+    "advent_of_code/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/fspoettel/advent-of-code-rust)"
+);
+
+#[derive(Debug)]
+pub enum AocClientError {
+    /// No session cookie found in `AOC_SESSION`, `ADVENT_OF_CODE_SESSION` or `~/.adventofcode.session`.
+    MissingSession,
+    /// The session cookie was rejected, it probably expired.
+    Unauthorized,
+    /// The puzzle does not exist or is not unlocked yet.
+    NotFound,
+    /// The server responded with an unexpected status code.
+    Http(u16),
+    /// The request could not be sent or the response could not be read.
+    Transport(String),
+    IoError(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::MissingSession => write!(
+                f,
+                "no session cookie found. Set AOC_SESSION or create \"~/.adventofcode.session\"."
+            ),
+            AocClientError::Unauthorized => {
+                write!(f, "the session cookie was rejected, it might have expired.")
+            }
+            AocClientError::NotFound => {
+                write!(f, "the puzzle does not exist or is not unlocked yet.")
+            }
+            AocClientError::Http(status) => {
+                write!(f, "the server responded with status {status}.")
+            }
+            AocClientError::Transport(e) => write!(f, "request failed: {e}"),
+            AocClientError::IoError(e) => {
+                write!(f, "could not write output files to file system: {e}")
+            }
+        }
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IoError(e)
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(400 | 401, _) => AocClientError::Unauthorized,
+            ureq::Error::Status(404, _) => AocClientError::NotFound,
+            ureq::Error::Status(status, _) => AocClientError::Http(status),
+            ureq::Error::Transport(e) => AocClientError::Transport(e.to_string()),
+        }
+    }
+}
+
+/// The response of the website to a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubmissionResponse {
+    /// The text of the response, stripped of markup.
+    pub message: String,
+}
+
+pub struct AocClient {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl AocClient {
+    #[must_use]
+    pub fn new(base_url: &str, session: &str) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        Self {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+        }
+    }
+
+    /// Creates a client from the environment:
+    ///  1. the session cookie is read from `AOC_SESSION`, `ADVENT_OF_CODE_SESSION` or `~/.adventofcode.session`.
+    ///  2. the base url is read from `AOC_BASE_URL` and defaults to `https://adventofcode.com`.
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let session = get_session().ok_or(AocClientError::MissingSession)?;
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+
+        Ok(Self::new(&base_url, &session))
+    }
+
+    /// Downloads the puzzle input of a day.
+    pub fn input(&self, date: AocDate) -> Result<String, AocClientError> {
+        self.get(&url_path(date, "/input"))
+    }
+
+    /// Downloads the puzzle description of a day, converted to plain text.
+    /// Includes part two if it has been unlocked.
+    pub fn puzzle(&self, date: AocDate) -> Result<String, AocClientError> {
+        let html = self.get(&url_path(date, ""))?;
+
+        Ok(extract_articles(&html).join("\n\n"))
+    }
+
+    /// Submits an answer for a part of a day.
+    pub fn submit(
+        &self,
+        date: AocDate,
+        part: u8,
+        answer: &str,
+    ) -> Result<SubmissionResponse, AocClientError> {
+        let html = self
+            .agent
+            .post(&self.url(&url_path(date, "/answer")))
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?
+            .into_string()?;
+
+        Ok(SubmissionResponse {
+            message: extract_articles(&html).join("\n\n"),
+        })
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}/{path}", self.base_url)
+    }
+
+    fn get(&self, path: &str) -> Result<String, AocClientError> {
+        Ok(self
+            .agent
+            .get(&self.url(path))
+            .set("Cookie", &format!("session={}", self.session))
+            .call()?
+            .into_string()?)
+    }
+}
+
+/// The path of a puzzle page, e.g. `2023/day/5/input`.
+fn url_path(date: AocDate, suffix: &str) -> String {
+    format!("{}/day/{}{suffix}", date.year, date.day.into_inner())
+}
+
+fn get_session() -> Option<String> {
+    if let Some(session) = ["AOC_SESSION", "ADVENT_OF_CODE_SESSION"]
+        .iter()
+        .find_map(|key| env::var(key).ok())
+    {
+        return Some(session);
+    }
+
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+    let session = fs::read_to_string(PathBuf::from(home).join(".adventofcode.session")).ok()?;
+
+    Some(session.trim().to_string()).filter(|s| !s.is_empty())
+}
+
+/// Extracts the text of all `<article>` elements of a page.
+fn extract_articles(html: &str) -> Vec<String> {
+    html.split("<article")
+        .skip(1)
+        .filter_map(|part| {
+            let (_, content) = part.split_once('>')?;
+            let (content, _) = content.split_once("</article>")?;
+            Some(html_to_text(content))
+        })
+        .collect()
+}
+
+/// Converts a fragment of puzzle markup to text, keeping paragraphs and list items on separate lines.
+fn html_to_text(html: &str) -> String {
+    let mut text = String::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        text.push_str(&rest[..start]);
+
+        let Some(end) = rest[start..].find('>') else {
+            break;
+        };
+
+        let tag = &rest[start + 1..start + end];
+        let name = tag
+            .trim_start_matches('/')
+            .split_whitespace()
+            .next()
+            .unwrap_or_default();
+
+        match (tag.starts_with('/'), name) {
+            (false, "li") => text.push_str("- "),
+            (true, "p" | "h2" | "li" | "pre" | "ul") | (false, "br") => text.push('\n'),
+            _ => {}
+        }
+
+        rest = &rest[start + end + 1..];
+    }
+
+    text.push_str(rest);
+
+    let text = text
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&");
+
+    text.trim().to_string()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+    };
+
+    use super::{extract_articles, AocClient, AocClientError};
+    use crate::{day, year, AocDate};
+
+    /// Serves a single request with a canned response and sends the raw request back to the test.
+    fn mock_server(status: &str, body: &str) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let response = format!(
+            "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut content_length = 0;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(len) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = len.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            request.push_str(&String::from_utf8(body).unwrap());
+
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+            tx.send(request).unwrap();
+        });
+
+        (base_url, rx)
+    }
+
+    #[test]
+    fn downloads_input() {
+        let (base_url, requests) = mock_server("200 OK", "1abc2\npqr3stu8vwx\n");
+        let client = AocClient::new(&base_url, "secret\n");
+
+        assert_eq!(
+            client.input(AocDate::new(year!(2023), day!(1))).unwrap(),
+            "1abc2\npqr3stu8vwx\n"
+        );
+
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("GET /2023/day/1/input HTTP/1.1"));
+        assert!(request.contains("session=secret\r\n"));
+    }
+
+    #[test]
+    fn downloads_puzzle() {
+        let (base_url, _) = mock_server(
+            "200 OK",
+            "<main><article class=\"day-desc\"><h2>--- Day 1 ---</h2><p>Calibrate &amp; <em>sum</em>:</p><ul><li>a</li><li>b</li></ul></article></main>",
+        );
+        let client = AocClient::new(&base_url, "secret");
+
+        assert_eq!(
+            client.puzzle(AocDate::new(year!(2023), day!(1))).unwrap(),
+            "--- Day 1 ---\nCalibrate & sum:\n- a\n- b"
+        );
+    }
+
+    #[test]
+    fn submits_answer() {
+        let (base_url, requests) = mock_server(
+            "200 OK",
+            "<article><p>That's the right answer!</p></article>",
+        );
+        let client = AocClient::new(&base_url, "secret");
+
+        let response = client
+            .submit(AocDate::new(year!(2023), day!(7)), 2, "5905")
+            .unwrap();
+        assert_eq!(response.message, "That's the right answer!");
+
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("POST /2023/day/7/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=5905"));
+    }
+
+    #[test]
+    fn maps_status_codes() {
+        let (base_url, _) = mock_server("404 Not Found", "");
+        let client = AocClient::new(&base_url, "secret");
+        assert!(matches!(
+            client.input(AocDate::new(year!(2023), day!(25))),
+            Err(AocClientError::NotFound)
+        ));
+
+        let (base_url, _) = mock_server("400 Bad Request", "");
+        let client = AocClient::new(&base_url, "secret");
+        assert!(matches!(
+            client.input(AocDate::new(year!(2023), day!(25))),
+            Err(AocClientError::Unauthorized)
+        ));
+
+        let (base_url, _) = mock_server("500 Internal Server Error", "");
+        let client = AocClient::new(&base_url, "secret");
+        assert!(matches!(
+            client.input(AocDate::new(year!(2023), day!(25))),
+            Err(AocClientError::Http(500))
+        ));
+    }
+
+    #[test]
+    fn extracts_all_articles() {
+        let html =
+            "<article><p>one</p></article><p>skip</p><article class=\"x\"><p>two</p></article>";
+        assert_eq!(extract_articles(html), vec!["one", "two"]);
+    }
+}
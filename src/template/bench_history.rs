@@ -0,0 +1,399 @@
+/// Module that keeps a history of benchmark runs in `data/<year>/bench_history.csv`.
+/// Every timed run appends one row per benched part, which allows detecting performance regressions over time.
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::template::runner::PartResult;
+use crate::template::stats::BenchStats;
+use crate::{Day, Year};
+
+static HEADER: &str =
+    "timestamp,commit,day,part,samples,min_ns,median_ns,p95_ns,max_ns,mean_ns,std_dev_ns,outliers";
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(msg) => write!(f, "{msg}"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+/// The benchmark statistics of one part in one timed run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    /// Seconds since the unix epoch at the time of the run.
+    pub timestamp: u64,
+    /// The abbreviated hash of the checked out git commit, if available.
+    pub commit: Option<String>,
+    pub day: Day,
+    pub part: u8,
+    pub stats: BenchStats,
+}
+
+impl Record {
+    /// The run that the record belongs to.
+    #[must_use]
+    pub fn run(&self) -> Run {
+        Run {
+            timestamp: self.timestamp,
+            commit: self.commit.clone(),
+        }
+    }
+}
+
+/// One invocation of `solve --time` or `all --time`, all records it appended share its timestamp and commit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub timestamp: u64,
+    pub commit: Option<String>,
+}
+
+/// What the records of the latest run are compared against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Baseline {
+    /// The most recent earlier run that benched the part.
+    Previous,
+    /// The earlier run with the lowest median of the part.
+    Best,
+}
+
+impl std::str::FromStr for Baseline {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "previous" => Ok(Self::Previous),
+            "best" => Ok(Self::Best),
+            _ => Err("expecting either `previous` or `best`".into()),
+        }
+    }
+}
+
+/// The comparison of a part of the latest run with its baseline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comparison {
+    pub day: Day,
+    pub part: u8,
+    pub baseline: Duration,
+    /// The run that the baseline was measured in.
+    pub baseline_run: Run,
+    pub latest: Duration,
+}
+
+impl Comparison {
+    /// The relative change of the median in percent, positive values mean the part got slower.
+    #[must_use]
+    pub fn change_percent(&self) -> f64 {
+        let baseline = self.baseline.as_secs_f64();
+
+        if baseline == 0.0 {
+            return 0.0;
+        }
+
+        (self.latest.as_secs_f64() - baseline) / baseline * 100.0
+    }
+
+    /// Whether the part got slower by more than `threshold` percent.
+    #[must_use]
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change_percent() > threshold
+    }
+}
+
+/// Builds history records for all benched parts that produced an answer.
+#[must_use]
+pub fn to_records(results: &[PartResult]) -> Vec<Record> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let commit = get_commit();
+
+    results
+        .iter()
+        .filter(|result| result.answer.solved().is_some())
+        .filter_map(|result| {
+            Some(Record {
+                timestamp,
+                commit: commit.clone(),
+                day: result.date.day,
+                part: result.part,
+                stats: result.stats?,
+            })
+        })
+        .collect()
+}
+
+#[must_use]
+pub fn get_path(year: Year) -> String {
+    format!("data/{year}/bench_history.csv")
+}
+
+/// Appends records to the history file of a year, creating it if it does not exist yet.
+pub fn append(year: Year, records: &[Record]) -> Result<(), Error> {
+    if records.is_empty() {
+        return Ok(());
+    }
+
+    let path = get_path(year);
+    let is_new = !Path::new(&path).exists();
+
+    if is_new {
+        fs::create_dir_all(format!("data/{year}"))?;
+    }
+
+    let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+
+    if is_new {
+        writeln!(file, "{HEADER}")?;
+    }
+
+    for record in records {
+        writeln!(file, "{}", serialize(record))?;
+    }
+
+    Ok(())
+}
+
+/// Reads all records from the history file of a year.
+pub fn read(year: Year) -> Result<Vec<Record>, Error> {
+    let path = get_path(year);
+
+    if !Path::new(&path).exists() {
+        return Ok(vec![]);
+    }
+
+    parse(&fs::read_to_string(&path)?, &path)
+}
+
+/// The run that appended the last records.
+#[must_use]
+pub fn latest_run(records: &[Record]) -> Option<Run> {
+    // records are appended, so the file order is chronological.
+    records.last().map(Record::run)
+}
+
+/// Compares every part of the latest run with its baseline in the earlier runs.
+/// Parts that were not benched before are skipped.
+#[must_use]
+pub fn compare(records: &[Record], baseline: Baseline) -> Vec<Comparison> {
+    let Some(latest_run) = latest_run(records) else {
+        return vec![];
+    };
+
+    let (mut latest, earlier): (Vec<&Record>, Vec<&Record>) =
+        records.iter().partition(|r| r.run() == latest_run);
+
+    latest.sort_by_key(|r| (r.day, r.part));
+
+    latest
+        .into_iter()
+        .filter_map(|record| {
+            let mut history = earlier
+                .iter()
+                .filter(|r| r.day == record.day && r.part == record.part);
+
+            let baseline = match baseline {
+                Baseline::Previous => history.next_back(),
+                Baseline::Best => history.min_by_key(|r| r.stats.median),
+            }?;
+
+            Some(Comparison {
+                day: record.day,
+                part: record.part,
+                baseline: baseline.stats.median,
+                baseline_run: baseline.run(),
+                latest: record.stats.median,
+            })
+        })
+        .collect()
+}
+
+fn get_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+
+    let commit = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    (output.status.success() && !commit.is_empty()).then_some(commit)
+}
+
+fn serialize(record: &Record) -> String {
+    let stats = &record.stats;
+
+    format!(
+        "{},{},{},{},{},{},{},{},{},{},{},{}",
+        record.timestamp,
+        record.commit.as_deref().unwrap_or(""),
+        record.day.into_inner(),
+        record.part,
+        stats.samples,
+        stats.min.as_nanos(),
+        stats.median.as_nanos(),
+        stats.p95.as_nanos(),
+        stats.max.as_nanos(),
+        stats.mean.as_nanos(),
+        stats.std_dev.as_nanos(),
+        stats.outliers
+    )
+}
+
+fn parse(s: &str, path: &str) -> Result<Vec<Record>, Error> {
+    s.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && *line != HEADER)
+        .map(|(i, line)| {
+            parse_record(line)
+                .ok_or_else(|| Error::Parser(format!("{path}:{}: malformed record.", i + 1)))
+        })
+        .collect()
+}
+
+fn parse_record(line: &str) -> Option<Record> {
+    let fields: Vec<&str> = line.split(',').collect();
+
+    let [timestamp, commit, day, part, numbers @ ..] = fields.as_slice() else {
+        return None;
+    };
+
+    let numbers = numbers
+        .iter()
+        .map(|n| n.parse::<u64>().ok())
+        .collect::<Option<Vec<u64>>>()?;
+
+    let [samples, min, median, p95, max, mean, std_dev, outliers] = numbers.as_slice() else {
+        return None;
+    };
+
+    Some(Record {
+        timestamp: timestamp.parse().ok()?,
+        commit: (!commit.is_empty()).then(|| (*commit).to_string()),
+        day: day.parse().ok()?,
+        part: part.parse().ok()?,
+        stats: BenchStats {
+            samples: u128::from(*samples),
+            min: Duration::from_nanos(*min),
+            median: Duration::from_nanos(*median),
+            p95: Duration::from_nanos(*p95),
+            max: Duration::from_nanos(*max),
+            mean: Duration::from_nanos(*mean),
+            std_dev: Duration::from_nanos(*std_dev),
+            outliers: u128::from(*outliers),
+        },
+    })
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{compare, latest_run, parse, serialize, Baseline, Record, HEADER};
+    use crate::day;
+    use crate::template::stats::BenchStats;
+
+    fn record(timestamp: u64, day: u8, part: u8, median_nanos: u64) -> Record {
+        Record {
+            timestamp,
+            commit: Some("abc1234".into()),
+            day: crate::Day::new(day).unwrap(),
+            part,
+            stats: BenchStats::from_samples(&[Duration::from_nanos(median_nanos)]).unwrap(),
+        }
+    }
+
+    #[test]
+    fn roundtrips_records() {
+        let mut without_commit = record(2, 25, 2, 500);
+        without_commit.commit = None;
+        let records = vec![record(1, 1, 1, 100), without_commit];
+
+        let file = std::iter::once(HEADER.to_string())
+            .chain(records.iter().map(serialize))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert_eq!(parse(&file, "bench_history.csv").unwrap(), records);
+    }
+
+    #[test]
+    fn rejects_malformed_records() {
+        assert!(parse("1,abc,1,1,10", "bench_history.csv").is_err());
+        assert!(parse("1,abc,26,1,1,1,1,1,1,1,1,0", "bench_history.csv").is_err());
+        assert!(parse("1,abc,1,1,1,1,1,1,1,1,x,0", "bench_history.csv").is_err());
+    }
+
+    #[test]
+    fn compares_with_previous_run() {
+        let records = vec![
+            record(1, 1, 1, 100),
+            record(1, 2, 1, 100),
+            record(2, 1, 1, 200),
+            record(3, 1, 1, 150),
+        ];
+        let comparisons = compare(&records, Baseline::Previous);
+
+        assert_eq!(comparisons.len(), 1);
+        assert_eq!(comparisons[0].day, day!(1));
+        assert_eq!(comparisons[0].baseline, Duration::from_nanos(200));
+        assert_eq!(comparisons[0].baseline_run.timestamp, 2);
+        assert_eq!(comparisons[0].latest, Duration::from_nanos(150));
+        assert!(comparisons[0].change_percent() < 0.0);
+        assert!(!comparisons[0].is_regression(10.0));
+    }
+
+    #[test]
+    fn compares_with_best_run() {
+        let records = vec![
+            record(1, 1, 2, 100),
+            record(2, 1, 2, 200),
+            record(3, 1, 2, 150),
+        ];
+        let comparisons = compare(&records, Baseline::Best);
+
+        assert_eq!(comparisons[0].part, 2);
+        assert_eq!(comparisons[0].baseline, Duration::from_nanos(100));
+        assert_eq!(comparisons[0].baseline_run.timestamp, 1);
+        assert_eq!(comparisons[0].change_percent(), 50.0);
+        assert!(comparisons[0].is_regression(10.0));
+        assert!(!comparisons[0].is_regression(50.0));
+    }
+
+    #[test]
+    fn only_compares_parts_of_the_latest_run() {
+        // day 1 was benched in earlier runs, the latest run only benched day 3.
+        let records = vec![
+            record(1, 1, 1, 100),
+            record(2, 1, 1, 300),
+            record(2, 3, 1, 100),
+            record(5, 3, 1, 120),
+            record(5, 3, 2, 80),
+        ];
+
+        assert_eq!(latest_run(&records).unwrap().timestamp, 5);
+
+        let comparisons = compare(&records, Baseline::Previous);
+        assert_eq!(comparisons.len(), 1);
+        assert_eq!((comparisons[0].day, comparisons[0].part), (day!(3), 1));
+        assert_eq!(comparisons[0].baseline_run.timestamp, 2);
+
+        assert!(compare(&[], Baseline::Best).is_empty());
+    }
+}
@@ -0,0 +1,159 @@
+/// Captures what the runner prints, so days that run concurrently can be printed in order once they finished.
+///
+/// The runner prints through [`out!`], [`outln!`] and [`errln!`]. They print to stdout and stderr as usual,
+/// unless the current thread runs inside [`capture`], then the output is recorded instead.
+use std::cell::RefCell;
+use std::io::{stdout, Write};
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// A piece of output and the stream it was printed to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Captured {
+    Stdout(String),
+    Stderr(String),
+}
+
+/// The output that a thread captures, shared with the worker threads it starts.
+pub type Capture = Arc<Mutex<Vec<Captured>>>;
+
+thread_local! {
+    static CAPTURE: RefCell<Option<Capture>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` and returns its result with everything the runner printed meanwhile on this thread.
+pub fn capture<T>(f: impl FnOnce() -> T) -> (T, Vec<Captured>) {
+    let capture = Capture::default();
+    let previous = CAPTURE.replace(Some(Arc::clone(&capture)));
+
+    let value = f();
+    CAPTURE.set(previous);
+
+    let captured = capture
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .drain(..)
+        .collect();
+
+    (value, captured)
+}
+
+/// Returns the capture of the current thread, to hand it to a worker thread with [`set_current`].
+#[must_use]
+pub fn current() -> Option<Capture> {
+    CAPTURE.with_borrow(Clone::clone)
+}
+
+/// Captures the output of the current thread, e.g. a worker that runs on behalf of a captured thread.
+pub fn set_current(capture: Option<Capture>) {
+    CAPTURE.set(capture);
+}
+
+/// Prints captured output to the streams it was printed to.
+pub fn replay(captured: &[Captured]) {
+    for output in captured {
+        match output {
+            Captured::Stdout(text) => print!("{text}"),
+            Captured::Stderr(text) => eprint!("{text}"),
+        }
+    }
+
+    let _ = stdout().flush();
+}
+
+/// Prints to stdout, or records the text if the current thread is captured.
+pub fn write_out(text: String) {
+    if let Some(text) = record(Captured::Stdout, text) {
+        print!("{text}");
+    }
+}
+
+/// Prints to stderr, or records the text if the current thread is captured.
+pub fn write_err(text: String) {
+    if let Some(text) = record(Captured::Stderr, text) {
+        eprint!("{text}");
+    }
+}
+
+/// Records the text if the current thread is captured, otherwise hands it back to be printed.
+fn record(stream: fn(String) -> Captured, text: String) -> Option<String> {
+    CAPTURE.with_borrow(|capture| {
+        let Some(capture) = capture else {
+            return Some(text);
+        };
+
+        capture
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(stream(text));
+        None
+    })
+}
+
+/// Like [`print!`], but captured inside [`capture`].
+macro_rules! out {
+    ($($arg:tt)*) => {
+        $crate::template::capture::write_out(format!($($arg)*))
+    };
+}
+
+/// Like [`println!`], but captured inside [`capture`].
+macro_rules! outln {
+    () => {
+        $crate::template::capture::write_out(String::from("\n"))
+    };
+    ($($arg:tt)*) => {
+        $crate::template::capture::write_out(format!("{}\n", format_args!($($arg)*)))
+    };
+}
+
+/// Like [`eprintln!`], but captured inside [`capture`].
+macro_rules! errln {
+    ($($arg:tt)*) => {
+        $crate::template::capture::write_err(format!("{}\n", format_args!($($arg)*)))
+    };
+}
+
+pub(crate) use {errln, out, outln};
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::thread;
+
+    use super::{capture, current, set_current, Captured};
+
+    #[test]
+    fn captures_output_in_order() {
+        let (value, captured) = capture(|| {
+            out!("Part 1: ");
+            outln!("{}", 42);
+            errln!("error: {}", "oops");
+            7
+        });
+
+        assert_eq!(value, 7);
+        assert_eq!(
+            captured,
+            vec![
+                Captured::Stdout("Part 1: ".into()),
+                Captured::Stdout("42\n".into()),
+                Captured::Stderr("error: oops\n".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn shares_captures_with_workers() {
+        let ((), captured) = capture(|| {
+            let shared = current();
+            thread::spawn(move || {
+                set_current(shared);
+                outln!("from a worker");
+            })
+            .join()
+            .unwrap();
+        });
+
+        assert_eq!(captured, vec![Captured::Stdout("from a worker\n".into())]);
+        assert!(current().is_none());
+    }
+}
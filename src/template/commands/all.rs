@@ -1,40 +1,90 @@
-use std::io;
-
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use crate::template::capture::{self, errln, outln, Captured};
+use crate::template::day_selection::DaySelection;
+use crate::template::timeout::WORKER_STACK_SIZE;
 use crate::template::{
+    bench_history,
+    output::{self, OutputFormat},
     readme_benchmarks::{self, Timings},
-    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+    registry::Registry,
+    runner::{Answer, PartResult, RunOptions},
+    try_read_file, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
 };
-use crate::{all_days, Day};
-
-pub fn handle(is_release: bool, is_timed: bool) {
+use crate::{AocDate, DaySet, Year};
+
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    registry: &Registry,
+    year: Year,
+    is_release: bool,
+    is_timed: bool,
+    format: OutputFormat,
+    timeout: Option<Duration>,
+    jobs: usize,
+    days: DaySelection,
+) {
     let mut timings: Vec<Timings> = vec![];
+    let mut results: Vec<PartResult> = vec![];
+    let is_human = format.is_human();
+
+    let options = RunOptions {
+        time: is_timed,
+        format,
+        timeout,
+        ..RunOptions::default()
+    };
+
+    let days = days.resolve(registry, year);
+    let dates: Vec<AocDate> = days.iter().map(|day| AocDate::new(year, day)).collect();
 
-    all_days().for_each(|day| {
-        if day > 1 {
-            println!();
+    let mut collect = |date: AocDate, day_results: Option<[PartResult; 2]>| {
+        if let Some(day_results) = day_results {
+            timings.push(collect_timings(&day_results, date));
+            results.extend(day_results);
         }
+    };
 
-        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-        println!("------");
+    // benchmarks run one at a time, days that run concurrently would slow each other down.
+    if jobs > 1 && !is_timed {
+        run_concurrently(registry, &dates, &options, jobs, collect);
+    } else {
+        for (index, &date) in dates.iter().enumerate() {
+            collect(date, run_day(registry, date, index == 0, &options));
+        }
+    }
 
-        let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+    output::print_results(format, &results);
 
-        if output.is_empty() {
-            println!("Not solved.");
-        } else {
-            let val = child_commands::parse_exec_time(&output, day);
-            timings.push(val);
+    if is_timed {
+        if let Err(e) = bench_history::append(year, &bench_history::to_records(&results)) {
+            eprintln!("Failed to record benchmark history: {e}");
         }
-    });
 
-    if is_timed {
         let total_millis = timings.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64;
 
-        println!("\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}");
+        if is_human {
+            println!(
+                "\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
+            );
+        }
+
+        // the table lists every solved day, it's only updated if all of them ran.
+        let solved: DaySet = registry
+            .iter(year)
+            .map(|solution| solution.date.day)
+            .collect();
 
-        if is_release {
+        if is_release && solved.intersection(days) != solved {
+            eprintln!("Skipped updating README, not all solved days were selected.");
+        } else if is_release {
             match readme_benchmarks::update(timings, total_millis) {
-                Ok(()) => println!("Successfully updated README with benchmarks."),
+                Ok(()) if is_human => println!("Successfully updated README with benchmarks."),
+                Ok(()) => eprintln!("Successfully updated README with benchmarks."),
                 Err(_) => {
                     eprintln!("Failed to update readme with benchmarks.");
                 }
@@ -43,212 +93,249 @@ pub fn handle(is_release: bool, is_timed: bool) {
     }
 }
 
-#[derive(Debug)]
-pub enum Error {
-    BrokenPipe,
-    Parser(String),
-    IO(io::Error),
-}
+/// Prints the header of a day and runs its solution, returns [`None`] if the day is not solved or its input is missing.
+fn run_day(
+    registry: &Registry,
+    date: AocDate,
+    is_first: bool,
+    options: &RunOptions,
+) -> Option<[PartResult; 2]> {
+    let day = date.day;
+    let is_human = options.format.is_human();
+
+    if is_human {
+        if !is_first {
+            outln!();
+        }
 
-impl From<std::io::Error> for Error {
-    fn from(e: std::io::Error) -> Self {
-        Error::IO(e)
+        outln!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        outln!("------");
     }
-}
-
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
 
-/// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
-mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::Day;
-    use std::{
-        io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
-        thread,
+    let Some(solution) = registry.get(date) else {
+        if is_human {
+            outln!("Not solved.");
+        }
+        return None;
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+    let input = match try_read_file("inputs", date) {
+        Ok(input) => input,
+        Err(e) => {
+            errln!("Failed to read input file for day {day}: {e}");
+            return None;
         }
+    };
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+    let day_results = solution.try_run(&input, options);
 
-        if is_release {
-            args.push("--release");
-        }
-
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
-        }
+    if day_results.is_none() {
+        errln!("Day {day} panicked, continuing with the next day.");
+    }
 
-        // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+    day_results
+}
 
-        let mut cmd = Command::new("cargo")
-            .args(&args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
+/// Runs days on up to `jobs` threads. The output of each day is captured and printed once the days before it
+/// were printed, so it's grouped and ordered like a sequential run.
+fn run_concurrently(
+    registry: &Registry,
+    dates: &[AocDate],
+    options: &RunOptions,
+    jobs: usize,
+    mut collect: impl FnMut(AocDate, Option<[PartResult; 2]>),
+) {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel::<(usize, Option<[PartResult; 2]>, Vec<Captured>)>();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(dates.len()) {
+            let (sender, next) = (sender.clone(), &next);
+
+            thread::Builder::new()
+                .stack_size(WORKER_STACK_SIZE)
+                .spawn_scoped(scope, move || loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(&date) = dates.get(index) else {
+                        break;
+                    };
+
+                    let (day_results, captured) =
+                        capture::capture(|| run_day(registry, date, index == 0, options));
+
+                    if sender.send((index, day_results, captured)).is_err() {
+                        break;
+                    }
+                })
+                .expect("could not start a worker thread");
+        }
 
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
+        drop(sender);
 
-        let mut output = vec![];
+        let mut finished = BTreeMap::new();
+        let mut next_to_print = 0;
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
-        });
+        for (index, day_results, captured) in receiver {
+            finished.insert(index, (day_results, captured));
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+            while let Some((day_results, captured)) = finished.remove(&next_to_print) {
+                capture::replay(&captured);
+                collect(dates[next_to_print], day_results);
+                next_to_print += 1;
+            }
         }
+    });
+}
 
-        thread.join().unwrap();
-        cmd.wait()?;
+/// Collects the benchmark statistics of all benched parts that produced an answer.
+fn collect_timings(results: &[PartResult], date: AocDate) -> Timings {
+    let mut timings = Timings {
+        date,
+        parse: None,
+        part_1: None,
+        part_2: None,
+        timed_out: [false; 2],
+        total_nanos: 0_f64,
+    };
 
-        Ok(output)
+    for result in results {
+        if result.answer == Answer::TimedOut {
+            timings.timed_out[usize::from(result.part) - 1] = true;
+        }
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timings {
-        let mut timings = super::Timings {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
-
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+    results
+        .iter()
+        .filter(|result| result.answer.solved().is_some())
+        .filter_map(|result| Some((result.part, result.stats?)))
+        .for_each(|(part, stats)| {
+            match part {
+                1 => timings.part_1 = Some(stats),
+                _ => timings.part_2 = Some(stats),
+            }
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
-
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
+            #[allow(clippy::cast_precision_loss)]
+            {
+                timings.total_nanos += stats.median.as_nanos() as f64;
+            }
+        });
 
-                timings.total_nanos += nanos;
-            });
+    // the parse stage is shared, it's counted once if any part was benched.
+    timings.parse = results
+        .iter()
+        .filter(|result| result.stats.is_some())
+        .find_map(|result| result.parse);
 
-        timings
+    #[allow(clippy::cast_precision_loss)]
+    if let Some(parse) = timings.parse {
+        timings.total_nanos += parse.as_nanos() as f64;
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    timings
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::collect_timings;
+    use crate::template::runner::{Answer, PartResult};
+    use crate::template::stats::BenchStats;
+    use crate::{day, year, AocDate};
+
+    fn result(part: u8, answer: Option<&str>, duration: Duration, samples: usize) -> PartResult {
+        let stats = (samples > 1)
+            .then(|| BenchStats::from_samples(&vec![duration; samples]))
+            .flatten();
+
+        PartResult {
+            duration,
+            samples: samples as u128,
+            stats,
+            ..PartResult::for_test(
+                AocDate::new(year!(2023), day!(1)),
+                part,
+                answer.map(Into::into).into(),
+            )
+        }
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+    #[test]
+    fn test_benched_parts() {
+        let res = collect_timings(
+            &[
+                result(1, Some("0"), Duration::from_nanos(74), 1000),
+                result(2, Some("10"), Duration::from_micros(74130), 999),
+            ],
+            AocDate::new(year!(2023), day!(1)),
+        );
+        assert_eq!(res.total_nanos, 74130074_f64);
+        assert_eq!(res.part_1.unwrap().median, Duration::from_nanos(74));
+        assert_eq!(res.part_2.unwrap().median, Duration::from_micros(74130));
     }
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
+    #[test]
+    fn test_parse_stage() {
+        let parsed = |part, samples| PartResult {
+            parse: Some(Duration::from_nanos(26)),
+            ..result(part, Some("0"), Duration::from_nanos(100), samples)
+        };
+
+        let res = collect_timings(
+            &[parsed(1, 100), parsed(2, 100)],
+            AocDate::new(year!(2023), day!(1)),
+        );
+        assert_eq!(res.parse, Some(Duration::from_nanos(26)));
+        assert_eq!(res.total_nanos, 226_f64);
+
+        let res = collect_timings(
+            &[parsed(1, 1), parsed(2, 1)],
+            AocDate::new(year!(2023), day!(1)),
+        );
+        assert_eq!(res.parse, None);
     }
 
-    #[cfg(feature = "test_lib")]
-    mod tests {
-        use super::parse_exec_time;
-
-        use crate::day;
-
-        #[test]
-        fn test_well_formed() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
-        }
+    #[test]
+    fn test_timed_out_parts() {
+        let mut timed_out = result(2, None, Duration::from_secs(10), 1);
+        timed_out.answer = Answer::TimedOut;
+
+        let res = collect_timings(
+            &[
+                result(1, Some("0"), Duration::from_nanos(74), 100),
+                timed_out,
+            ],
+            AocDate::new(year!(2023), day!(1)),
+        );
+        assert_eq!(res.timed_out, [false, true]);
+        assert_eq!(res.total_nanos, 74_f64);
+    }
 
-        #[test]
-        fn test_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
-        }
+    #[test]
+    fn test_unbenched_parts() {
+        let res = collect_timings(
+            &[
+                result(1, Some("0"), Duration::from_nanos(74), 1),
+                result(2, Some("10"), Duration::from_millis(74), 1),
+            ],
+            AocDate::new(year!(2023), day!(1)),
+        );
+        assert_eq!(res.total_nanos, 0_f64);
+        assert_eq!(res.part_1.is_none(), true);
+        assert_eq!(res.part_2.is_none(), true);
+    }
 
-        #[test]
-        fn test_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
-        }
+    #[test]
+    fn test_missing_parts() {
+        let res = collect_timings(
+            &[
+                result(1, None, Duration::from_nanos(74), 100),
+                result(2, None, Duration::from_millis(74), 100),
+            ],
+            AocDate::new(year!(2023), day!(1)),
+        );
+        assert_eq!(res.total_nanos, 0_f64);
+        assert_eq!(res.part_1.is_none(), true);
+        assert_eq!(res.part_2.is_none(), true);
     }
 }
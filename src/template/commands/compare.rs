@@ -0,0 +1,81 @@
+use std::process;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::template::bench_history::{self, Baseline, Run};
+use crate::template::day_selection::DaySelection;
+use crate::template::registry::Registry;
+use crate::template::{ANSI_BOLD, ANSI_RESET};
+use crate::Year;
+
+pub fn handle(
+    registry: &Registry,
+    year: Year,
+    baseline: Baseline,
+    threshold: f64,
+    days: DaySelection,
+) {
+    let records = match bench_history::read(year) {
+        Ok(records) => records,
+        Err(e) => {
+            eprintln!("Failed to read benchmark history: {e}");
+            process::exit(1);
+        }
+    };
+
+    let days = days.resolve(registry, year);
+    let mut comparisons = bench_history::compare(&records, baseline);
+    comparisons.retain(|comparison| days.contains(comparison.day));
+
+    if comparisons.is_empty() {
+        println!("Not enough benchmark history to compare. Run `cargo time` at least twice.");
+        return;
+    }
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+
+    if let Some(run) = bench_history::latest_run(&records) {
+        println!("Latest run: {}", format_run(&run, now));
+    }
+
+    let mut regressions = 0;
+
+    for comparison in &comparisons {
+        let is_regression = comparison.is_regression(threshold);
+        regressions += usize::from(is_regression);
+
+        println!(
+            "{ANSI_BOLD}Day {} Part {}{ANSI_RESET}: {:.1?} → {:.1?} ({:+.1}% vs {}){}",
+            comparison.day,
+            comparison.part,
+            comparison.baseline,
+            comparison.latest,
+            comparison.change_percent(),
+            format_run(&comparison.baseline_run, now),
+            if is_regression { " ✖ regression" } else { "" }
+        );
+    }
+
+    println!("---");
+    println!("{regressions} regression(s) beyond {threshold}%.");
+
+    if regressions > 0 {
+        process::exit(1);
+    }
+}
+
+/// Describes a run by its commit and age, e.g. `abc1234, 2d ago`.
+fn format_run(run: &Run, now: u64) -> String {
+    let commit = run.commit.as_deref().unwrap_or("unknown commit");
+    let age = now.saturating_sub(run.timestamp);
+
+    let age = match age {
+        0..60 => format!("{age}s"),
+        60..3600 => format!("{}m", age / 60),
+        3600..86400 => format!("{}h", age / 3600),
+        _ => format!("{}d", age / 86400),
+    };
+
+    format!("{commit}, {age} ago")
+}
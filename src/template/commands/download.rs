@@ -1,15 +1,19 @@
-use crate::template::aoc_cli;
-use crate::Day;
 use std::process;
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
+use crate::template::aoc_backend::AocBackend;
+use crate::AocDate;
+
+pub fn handle(date: AocDate) {
+    let backend = match AocBackend::detect() {
+        Ok(backend) => backend,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+    if let Err(e) = backend.download(date) {
+        eprintln!("failed to download via {backend}: {e}");
         process::exit(1);
     };
 }
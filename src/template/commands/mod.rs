@@ -1,5 +1,8 @@
 pub mod all;
+pub mod compare;
 pub mod download;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
+pub mod verify;
+pub mod watch;
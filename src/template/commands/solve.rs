@@ -1,31 +1,59 @@
-use std::process::{Command, Stdio};
-
-use crate::Day;
-
-pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
-
-    if release {
-        cmd_args.push("--release".to_string());
-    }
-
-    cmd_args.push("--".to_string());
-
-    if let Some(submit_part) = submit_part {
-        cmd_args.push("--submit".to_string());
-        cmd_args.push(submit_part.to_string());
+use std::process;
+use std::time::Duration;
+
+use crate::template::bench_history;
+use crate::template::input::InputSource;
+use crate::template::output::{self, OutputFormat};
+use crate::template::registry::Registry;
+use crate::template::runner::RunOptions;
+use crate::AocDate;
+
+pub fn handle(
+    registry: &Registry,
+    date: AocDate,
+    source: &InputSource,
+    time: bool,
+    submit_part: Option<u8>,
+    format: OutputFormat,
+    timeout: Option<Duration>,
+) {
+    let (year, day) = (date.year, date.day);
+
+    let Some(solution) = registry.get(date) else {
+        eprintln!(
+            "No solution found for day {day} of {year}. Try running `cargo scaffold {day} --year {year}` first."
+        );
+        process::exit(1);
+    };
+
+    if submit_part.is_some() && !source.is_puzzle() {
+        eprintln!("--submit can only be used with the puzzle input.");
+        process::exit(1);
     }
 
-    if time {
-        cmd_args.push("--time".to_string());
+    let input = match source.read(date) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Day {day} of {year}: {e}");
+            process::exit(1);
+        }
+    };
+
+    let options = RunOptions {
+        time,
+        submit: submit_part,
+        format,
+        timeout,
+        ..RunOptions::default()
+    };
+
+    let results = solution.run(&input, &options);
+    output::print_results(format, &results);
+
+    // timings of other inputs are not comparable, only puzzle inputs are recorded.
+    if time && source.is_puzzle() {
+        if let Err(e) = bench_history::append(year, &bench_history::to_records(&results)) {
+            eprintln!("Failed to record benchmark history: {e}");
+        }
     }
-
-    let mut cmd = Command::new("cargo")
-        .args(&cmd_args)
-        .stdout(Stdio::inherit())
-        .stderr(Stdio::inherit())
-        .spawn()
-        .unwrap();
-
-    cmd.wait().unwrap();
 }
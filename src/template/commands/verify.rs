@@ -0,0 +1,154 @@
+use std::process;
+
+use crate::template::{
+    answers,
+    day_selection::DaySelection,
+    registry::Registry,
+    runner::{Answer, PartResult, RunOptions},
+    try_read_file, ANSI_BOLD, ANSI_RESET,
+};
+use crate::Year;
+
+/// The outcome of checking a part against its accepted answer.
+#[derive(Debug, PartialEq, Eq)]
+enum Verification {
+    /// The part produced the accepted answer.
+    Pass,
+    /// The part did not produce an answer although one was accepted.
+    Fail,
+    /// The part produced an answer that differs from the accepted one.
+    Changed { expected: String },
+    /// No answer was accepted for this part yet.
+    Unrecorded,
+}
+
+fn verify_part(result: &PartResult, expected: Option<&str>) -> Verification {
+    match (expected, result.answer.solved()) {
+        (None, _) => Verification::Unrecorded,
+        (Some(expected), Some(actual)) if expected == actual => Verification::Pass,
+        (Some(_), None) => Verification::Fail,
+        (Some(expected), Some(_)) => Verification::Changed {
+            expected: expected.into(),
+        },
+    }
+}
+
+pub fn handle(registry: &Registry, year: Year, record: bool, days: DaySelection) {
+    let options = RunOptions {
+        quiet: true,
+        ..RunOptions::default()
+    };
+
+    let (mut passed, mut failed, mut changed) = (0, 0, 0);
+
+    let days = days.resolve(registry, year);
+
+    for solution in registry.iter(year).filter(|s| days.contains(s.date.day)) {
+        let date = solution.date;
+        let day = date.day;
+
+        let mut answers = match answers::read(date) {
+            Ok(answers) => answers.unwrap_or_default(),
+            Err(e) => {
+                eprintln!(
+                    "Day {day}: failed to read \"{}\": {e}",
+                    answers::get_path(date)
+                );
+                failed += 1;
+                continue;
+            }
+        };
+
+        let Ok(input) = try_read_file("inputs", date) else {
+            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}: input file missing, skipped.");
+            continue;
+        };
+
+        let mut recorded = false;
+
+        let Some(results) = solution.try_run(&input, &options) else {
+            failed += 1;
+            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}: ✖ fail (the solution panicked)");
+            continue;
+        };
+
+        for result in results {
+            let part = result.part;
+            let answer = result.answer.solved().map_or("✖", String::as_str);
+            let label = format!("{ANSI_BOLD}Day {day}{ANSI_RESET} Part {part}");
+
+            match verify_part(&result, answers.get(part)) {
+                Verification::Pass => {
+                    passed += 1;
+                    println!("{label}: ✔ pass ({answer})");
+                }
+                Verification::Fail => {
+                    failed += 1;
+                    let reason = result.answer.error().unwrap_or("no answer");
+                    println!("{label}: ✖ fail ({reason})");
+                }
+                Verification::Changed { expected } => {
+                    changed += 1;
+                    println!("{label}: ✖ changed (expected {expected}, got {answer})");
+                }
+                Verification::Unrecorded => match result.answer {
+                    Answer::Solved(answer) if record => {
+                        println!("{label}: recorded {answer}");
+                        answers.set(part, answer);
+                        recorded = true;
+                    }
+                    _ => println!("{label}: - no accepted answer"),
+                },
+            }
+        }
+
+        if recorded {
+            if let Err(e) = answers::write(date, &answers) {
+                eprintln!(
+                    "Day {day}: failed to write \"{}\": {e}",
+                    answers::get_path(date)
+                );
+                process::exit(1);
+            }
+        }
+    }
+
+    println!("---");
+    println!("{passed} passed, {failed} failed, {changed} changed.");
+
+    if failed > 0 || changed > 0 {
+        process::exit(1);
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{verify_part, Verification};
+    use crate::template::runner::PartResult;
+    use crate::{day, year, AocDate};
+
+    fn result(answer: Option<&str>) -> PartResult {
+        let date = AocDate::new(year!(2023), day!(1));
+        PartResult::for_test(date, 1, answer.map(Into::into).into())
+    }
+
+    #[test]
+    fn verifies_parts() {
+        assert_eq!(
+            verify_part(&result(Some("42")), Some("42")),
+            Verification::Pass
+        );
+        assert_eq!(verify_part(&result(None), Some("42")), Verification::Fail);
+        assert_eq!(
+            verify_part(&result(Some("41")), Some("42")),
+            Verification::Changed {
+                expected: "42".into()
+            }
+        );
+        assert_eq!(
+            verify_part(&result(Some("42")), None),
+            Verification::Unrecorded
+        );
+        assert_eq!(verify_part(&result(None), None), Verification::Unrecorded);
+    }
+}
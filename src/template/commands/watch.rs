@@ -0,0 +1,222 @@
+use std::{
+    env, fs,
+    path::PathBuf,
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::template::output::{answer_from_json, JsonAnswer};
+use crate::template::{get_data_path, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::AocDate;
+
+/// How often the watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Re-runs the solution of a day (or its tests) whenever its source, examples or input change.
+/// Files are polled instead of using platform-specific notifiers, so this works everywhere.
+pub fn handle(date: AocDate, run_tests: bool) {
+    let bin = format!("{}-{}", date.year, date.day);
+    let mut snapshot = None;
+    let mut previous: Vec<JsonAnswer> = vec![];
+
+    println!(
+        "👀 Watching day {} of {}, press Ctrl+C to stop.",
+        date.day, date.year
+    );
+
+    loop {
+        let current = snapshot_files(date);
+
+        if snapshot.as_ref() != Some(&current) {
+            if snapshot.is_some() {
+                println!("---");
+            }
+
+            snapshot = Some(current);
+
+            if run_tests {
+                run_bin_tests(&bin);
+            } else if let Some(answers) = run_solution(&bin) {
+                for answer in &answers {
+                    let before = previous.iter().find(|p| p.part == answer.part);
+                    println!("{}", format_answer(before, answer));
+                }
+
+                previous = answers;
+            }
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// The source file of the day, its example files and its input.
+fn watched_files(date: AocDate) -> Vec<PathBuf> {
+    let mut files = vec![
+        PathBuf::from(format!("src/bin/{}-{}.rs", date.year, date.day)),
+        get_data_path("inputs", date, &format!("{}.txt", date.day)),
+    ];
+
+    // examples might be split by part, e.g. `01-1.txt` and `01-2.txt`.
+    let examples = get_data_path("examples", date, "");
+
+    if let Ok(entries) = fs::read_dir(examples) {
+        let prefix = date.day.to_string();
+
+        let mut example_files: Vec<PathBuf> = entries
+            .filter_map(|entry| Some(entry.ok()?.path()))
+            .filter(|path| {
+                path.extension().is_some_and(|ext| ext == "txt")
+                    && path
+                        .file_stem()
+                        .and_then(|stem| stem.to_str())
+                        .is_some_and(|stem| stem.starts_with(&prefix))
+            })
+            .collect();
+
+        example_files.sort();
+        files.append(&mut example_files);
+    }
+
+    files
+}
+
+/// The modification times of all watched files, missing files have none.
+fn snapshot_files(date: AocDate) -> Vec<(PathBuf, Option<SystemTime>)> {
+    watched_files(date)
+        .into_iter()
+        .map(|path| {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok();
+            (path, modified)
+        })
+        .collect()
+}
+
+/// Creates a cargo command without the variables that `cargo run` sets for this binary.
+/// Otherwise dependencies whose build scripts track variables like `CARGO_MANIFEST_DIR`
+/// are rebuilt every time the watched solution runs, and again by the next regular build.
+fn cargo() -> Command {
+    let mut command = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".into()));
+
+    for (key, _) in env::vars_os() {
+        let Some(key) = key.to_str() else {
+            continue;
+        };
+
+        if key == "OUT_DIR"
+            || key.starts_with("CARGO_PKG_")
+            || key.starts_with("CARGO_MANIFEST_")
+            || matches!(
+                key,
+                "CARGO_CRATE_NAME" | "CARGO_BIN_NAME" | "CARGO_PRIMARY_PACKAGE"
+            )
+        {
+            command.env_remove(key);
+        }
+    }
+
+    command
+}
+
+/// Rebuilds and runs the binary of a day, returns [`None`] if it failed to build or run.
+fn run_solution(bin: &str) -> Option<Vec<JsonAnswer>> {
+    let output = cargo()
+        .args(["run", "--quiet", "--release", "--bin", bin, "--"])
+        .args(["--format", "jsonl"])
+        .stderr(Stdio::inherit())
+        .output();
+
+    match output {
+        Ok(output) if output.status.success() => Some(
+            String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .filter_map(answer_from_json)
+                .collect(),
+        ),
+        Ok(_) => {
+            eprintln!("✖ the solution failed to build or run.");
+            None
+        }
+        Err(e) => {
+            eprintln!("✖ could not call cargo: {e}");
+            None
+        }
+    }
+}
+
+fn run_bin_tests(bin: &str) {
+    let status = cargo().args(["test", "--quiet", "--bin", bin]).status();
+
+    match status {
+        Ok(status) if status.success() => println!("✔ tests passed."),
+        Ok(_) => println!("✖ tests failed."),
+        Err(e) => eprintln!("✖ could not call cargo: {e}"),
+    }
+}
+
+/// Formats the answer of a part, marking whether it changed since the previous run.
+fn format_answer(previous: Option<&JsonAnswer>, current: &JsonAnswer) -> String {
+    let answer = current.answer.as_deref().unwrap_or("✖");
+
+    let change = match previous.map(|p| p.answer.as_deref()) {
+        None => String::new(),
+        Some(before) if before == current.answer.as_deref() => " (unchanged)".into(),
+        Some(before) => format!(" (was {})", before.unwrap_or("✖")),
+    };
+
+    let duration = format!("{ANSI_ITALIC}({:.1?}){ANSI_RESET}", current.duration);
+
+    if answer.contains('\n') {
+        format!("Part {}: ▼{change} {duration}\n{answer}", current.part)
+    } else {
+        format!(
+            "Part {}: {ANSI_BOLD}{answer}{ANSI_RESET}{change} {duration}",
+            current.part
+        )
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{path::PathBuf, time::Duration};
+
+    use super::{format_answer, watched_files};
+    use crate::template::output::JsonAnswer;
+    use crate::{day, year, AocDate};
+
+    fn answer(answer: Option<&str>) -> JsonAnswer {
+        JsonAnswer {
+            part: 1,
+            answer: answer.map(Into::into),
+            duration: Duration::from_micros(10),
+        }
+    }
+
+    #[test]
+    fn marks_changed_answers() {
+        let first = format_answer(None, &answer(Some("42")));
+        assert!(first.contains("42"));
+        assert!(!first.contains("unchanged") && !first.contains("was"));
+
+        let unchanged = format_answer(Some(&answer(Some("42"))), &answer(Some("42")));
+        assert!(unchanged.contains("(unchanged)"));
+
+        let changed = format_answer(Some(&answer(Some("41"))), &answer(Some("42")));
+        assert!(changed.contains("(was 41)"));
+
+        let solved = format_answer(Some(&answer(None)), &answer(Some("42")));
+        assert!(solved.contains("(was ✖)"));
+    }
+
+    #[test]
+    fn watches_source_input_and_examples() {
+        let files = watched_files(AocDate::new(year!(2023), day!(1)));
+
+        assert_eq!(files[0], PathBuf::from("src/bin/2023-01.rs"));
+        assert_eq!(files[1], PathBuf::from("data/2023/inputs/01.txt"));
+        assert!(files.contains(&PathBuf::from("data/2023/examples/01-1.txt")));
+        assert!(files.contains(&PathBuf::from("data/2023/examples/01-2.txt")));
+        assert!(!files.iter().any(|f| f.ends_with("02.txt")));
+    }
+}
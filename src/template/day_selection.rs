@@ -0,0 +1,148 @@
+/// The days a command runs, as selected with `--days`.
+///
+/// A selection is a comma separated list of days and ranges like `1-5,7,12-`, and the keywords `all`,
+/// `solved`, `unsolved` and `latest`. Keywords depend on the registered solutions, so a selection is
+/// resolved to a [`DaySet`] against the registry of a year.
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::template::registry::Registry;
+use crate::{DaySet, Year};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DaySelection {
+    days: DaySet,
+    solved: bool,
+    unsolved: bool,
+    latest: bool,
+}
+
+impl DaySelection {
+    /// Returns the selected days of a year.
+    #[must_use]
+    pub fn resolve(&self, registry: &Registry, year: Year) -> DaySet {
+        let solved: DaySet = registry
+            .iter(year)
+            .map(|solution| solution.date.day)
+            .collect();
+        let mut days = self.days;
+
+        if self.solved {
+            days = days.union(solved);
+        }
+
+        if self.unsolved {
+            days = days.union(solved.complement());
+        }
+
+        if let Some(latest) = solved.last().filter(|_| self.latest) {
+            days.insert(latest);
+        }
+
+        days
+    }
+}
+
+impl Default for DaySelection {
+    fn default() -> Self {
+        Self {
+            days: DaySet::ALL,
+            solved: false,
+            unsolved: false,
+            latest: false,
+        }
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = DaySelectionFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut selection = Self {
+            days: DaySet::EMPTY,
+            ..Self::default()
+        };
+
+        for item in s.split(',').map(str::trim) {
+            match item {
+                "all" => selection.days = DaySet::ALL,
+                "solved" => selection.solved = true,
+                "unsolved" => selection.unsolved = true,
+                "latest" => selection.latest = true,
+                _ => {
+                    let days: DaySet = item
+                        .parse()
+                        .map_err(|_| DaySelectionFromStrError(item.into()))?;
+                    selection.days = selection.days.union(days);
+                }
+            }
+        }
+
+        Ok(selection)
+    }
+}
+
+/// An error which can be returned when parsing a [`DaySelection`], holds the item that is invalid.
+#[derive(Debug)]
+pub struct DaySelectionFromStrError(pub String);
+
+impl Error for DaySelectionFromStrError {}
+
+impl Display for DaySelectionFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid day selection `{}`, expecting days and ranges like `1-5,7,12-` or `all`, `solved`, `unsolved`, `latest`",
+            self.0
+        )
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::DaySelection;
+    use crate::template::registry::{Registry, Solution};
+    use crate::template::runner::{PartResult, RunOptions};
+    use crate::{day, year, AocDate, Day};
+
+    fn unused(_: &str, _: &RunOptions) -> [PartResult; 2] {
+        unreachable!()
+    }
+
+    const fn solution(day: Day) -> Solution {
+        Solution {
+            date: AocDate::new(year!(2023), day),
+            runner: unused,
+            timeout: None,
+        }
+    }
+
+    static SOLUTIONS: [Solution; 3] = [solution(day!(1)), solution(day!(2)), solution(day!(5))];
+
+    fn resolve(s: &str) -> Vec<u8> {
+        let selection: DaySelection = s.parse().unwrap();
+        selection
+            .resolve(&Registry::new(&SOLUTIONS), year!(2023))
+            .iter()
+            .map(Day::into_inner)
+            .collect()
+    }
+
+    #[test]
+    fn resolves_keywords() {
+        assert_eq!(resolve("solved"), [1, 2, 5]);
+        assert_eq!(resolve("latest"), [5]);
+        assert_eq!(resolve("unsolved,1-2").len(), 24);
+        assert_eq!(resolve("3-6,solved"), [1, 2, 3, 4, 5, 6]);
+        assert_eq!(resolve("all").len(), 25);
+        assert_eq!(DaySelection::default(), "all".parse().unwrap());
+    }
+
+    #[test]
+    fn rejects_unknown_items() {
+        assert!("newest".parse::<DaySelection>().is_err());
+        assert!("1-5,".parse::<DaySelection>().is_err());
+        assert!("".parse::<DaySelection>().is_err());
+    }
+}
@@ -0,0 +1,181 @@
+/// Selects the input that a solution runs against.
+use std::{
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    path::PathBuf,
+};
+
+use crate::template::get_data_path;
+use crate::AocDate;
+
+/// Where the input of a solution is read from.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum InputSource {
+    /// The puzzle input in `data/<year>/inputs`.
+    #[default]
+    Puzzle,
+    /// An example in `data/<year>/examples`, optionally the one with a part suffix, e.g. `01-2.txt`.
+    Example(Option<u8>),
+    /// An arbitrary file.
+    File(PathBuf),
+    /// The standard input of the process.
+    Stdin,
+}
+
+/// An error which can be returned when an input can't be read.
+#[derive(Debug)]
+pub struct InputError {
+    /// A description of the input that could not be read, e.g. its path.
+    pub input: String,
+    pub error: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "failed to read {}: {}", self.input, self.error)
+    }
+}
+
+impl InputSource {
+    /// Reads `--input <path>` (`-` for stdin) and `--example [part]` from command-line arguments.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        let example = match args.opt_value_from_str::<_, u8>("--example") {
+            Ok(Some(part)) => Some(Some(part)),
+            // `--example` without a part number, it's the last argument or followed by another flag.
+            Ok(None) | Err(_) => args.contains("--example").then_some(None),
+        };
+
+        let input: Option<String> = args.opt_value_from_str("--input")?;
+
+        Ok(match (example, input) {
+            (Some(part), _) => Self::Example(part),
+            (None, Some(path)) if path == "-" => Self::Stdin,
+            (None, Some(path)) => Self::File(path.into()),
+            (None, None) => Self::Puzzle,
+        })
+    }
+
+    /// Reads the input source from the command-line arguments of the current process.
+    #[must_use]
+    pub fn from_env() -> Self {
+        let mut args = pico_args::Arguments::from_env();
+
+        Self::from_args(&mut args).unwrap_or_else(|e| {
+            eprintln!("Unexpected command-line input: {e}");
+            std::process::exit(1);
+        })
+    }
+
+    /// Whether this is the puzzle input, i.e. results can be submitted and benchmarks recorded.
+    #[must_use]
+    pub fn is_puzzle(&self) -> bool {
+        *self == Self::Puzzle
+    }
+
+    /// Returns the path that is read for a day, or [`None`] for stdin.
+    #[must_use]
+    pub fn path(&self, date: AocDate) -> Option<PathBuf> {
+        let day = date.day;
+
+        match self {
+            Self::Puzzle => Some(get_data_path("inputs", date, &format!("{day}.txt"))),
+            Self::Example(None) => Some(get_data_path("examples", date, &format!("{day}.txt"))),
+            Self::Example(Some(part)) => Some(get_data_path(
+                "examples",
+                date,
+                &format!("{day}-{part}.txt"),
+            )),
+            Self::File(path) => Some(path.clone()),
+            Self::Stdin => None,
+        }
+    }
+
+    /// Reads the input for a day.
+    pub fn read(&self, date: AocDate) -> Result<String, InputError> {
+        let Some(path) = self.path(date) else {
+            let mut input = String::new();
+
+            return io::stdin()
+                .read_to_string(&mut input)
+                .map(|_| input)
+                .map_err(|error| InputError {
+                    input: "stdin".into(),
+                    error,
+                });
+        };
+
+        fs::read_to_string(&path).map_err(|error| InputError {
+            input: format!("\"{}\"", path.display()),
+            error,
+        })
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::InputSource;
+    use crate::{day, year, AocDate};
+
+    const DATE: AocDate = AocDate::new(year!(2023), day!(1));
+
+    fn parse(args: &[&str]) -> InputSource {
+        let mut args = pico_args::Arguments::from_vec(args.iter().map(Into::into).collect());
+        InputSource::from_args(&mut args).unwrap()
+    }
+
+    #[test]
+    fn parses_sources() {
+        assert_eq!(parse(&["01"]), InputSource::Puzzle);
+        assert_eq!(parse(&["01", "--example"]), InputSource::Example(None));
+        assert_eq!(
+            parse(&["01", "--example", "--time"]),
+            InputSource::Example(None)
+        );
+        assert_eq!(
+            parse(&["01", "--example", "2"]),
+            InputSource::Example(Some(2))
+        );
+        assert_eq!(
+            parse(&["01", "--input", "other.txt"]),
+            InputSource::File("other.txt".into())
+        );
+        assert_eq!(parse(&["01", "--input", "-"]), InputSource::Stdin);
+    }
+
+    #[test]
+    fn leaves_other_arguments() {
+        let mut args =
+            pico_args::Arguments::from_vec(vec!["01".into(), "--example".into(), "--time".into()]);
+        InputSource::from_args(&mut args).unwrap();
+
+        assert!(args.contains("--time"));
+        assert_eq!(args.free_from_str::<String>().unwrap(), "01");
+    }
+
+    #[test]
+    fn resolves_paths() {
+        assert_eq!(
+            InputSource::Puzzle.path(DATE),
+            Some(PathBuf::from("data/2023/inputs/01.txt"))
+        );
+        assert_eq!(
+            InputSource::Example(Some(2)).path(DATE),
+            Some(PathBuf::from("data/2023/examples/01-2.txt"))
+        );
+        assert_eq!(InputSource::Stdin.path(DATE), None);
+    }
+
+    #[test]
+    fn reads_examples() {
+        assert!(InputSource::Example(Some(1))
+            .read(DATE)
+            .unwrap()
+            .starts_with("1abc2"));
+
+        let error = InputSource::Example(Some(3)).read(DATE).unwrap_err();
+        assert!(error.to_string().contains("data/2023/examples/01-3.txt"));
+    }
+}
@@ -1,48 +1,134 @@
-use crate::Day;
-use std::{env, fs};
+use crate::AocDate;
+use std::{env, fs, io, path::PathBuf};
 
+pub mod answers;
+pub mod aoc_backend;
 pub mod aoc_cli;
+pub mod aoc_client;
+pub mod bench_history;
+pub mod capture;
 pub mod commands;
+pub mod day_selection;
+pub mod input;
+pub mod output;
 pub mod readme_benchmarks;
+pub mod registry;
 pub mod runner;
+pub mod stats;
+pub mod submissions;
+pub mod timeout;
+
+pub use registry::Solution;
+pub use runner::{Answer, Detailed};
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// Returns the path of a file of a day in the data folder of its year, e.g. `data/2023/inputs/05.txt`.
+#[must_use]
+pub fn get_data_path(folder: &str, date: AocDate, file_name: &str) -> PathBuf {
+    PathBuf::from("data")
+        .join(date.year.to_string())
+        .join(folder)
+        .join(file_name)
+}
+
 /// Helper function that reads a text file to a string.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+pub fn read_file(folder: &str, date: AocDate) -> String {
+    try_read_file(folder, date).expect("could not open input file")
+}
+
+/// Helper function that reads a text file to a string, returning an error instead of panicking if it can't be read.
+pub fn try_read_file(folder: &str, date: AocDate) -> io::Result<String> {
+    let cwd = env::current_dir()?;
+    let filepath = cwd.join(get_data_path(folder, date, &format!("{}.txt", date.day)));
+    fs::read_to_string(filepath)
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+pub fn read_file_part(folder: &str, date: AocDate, part: u8) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd
-        .join("data")
-        .join(folder)
-        .join(format!("{day}-{part}.txt"));
+    let filepath = cwd.join(get_data_path(
+        folder,
+        date,
+        &format!("{}-{part}.txt", date.day),
+    ));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Creates the constants `DATE` and `DAY`, registers the solution as `SOLUTION` and sets up the input and runner for each part.
+///
+/// With `parse = <function>`, the input is parsed once by that function and both parts receive a reference to its output.
+/// The parse function returns a `Result`, its time is reported separately from the parts.
+///
+/// With `timeout = <duration>`, e.g. `timeout = std::time::Duration::from_secs(60)`, each part gets this time budget
+/// instead of the one passed to the command with `--timeout`.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
+    ($year:expr, $day:expr $(, timeout = $timeout:expr)?) => {
+        advent_of_code::solution!(@define $year, $day, [$($timeout)?], |input, options| {
+            [
+                advent_of_code::template::runner::run_part(part_one, input, DATE, 1, options),
+                advent_of_code::template::runner::run_part(part_two, input, DATE, 2, options),
+            ]
+        });
+    };
+    ($year:expr, $day:expr, parse = $parse:expr $(, timeout = $timeout:expr)?) => {
+        advent_of_code::solution!(@define $year, $day, [$($timeout)?], |input, options| {
+            advent_of_code::template::runner::run_parsed(
+                $parse,
+                |parsed| part_one(parsed),
+                |parsed| part_two(parsed),
+                input,
+                DATE,
+                options,
+            )
+        });
+    };
+    (@timeout []) => {
+        None
+    };
+    (@timeout [$timeout:expr]) => {
+        Some($timeout)
+    };
+    (@define $year:expr, $day:expr, [$($timeout:expr)?], $runner:expr) => {
+        /// The year and day of the current solution.
+        const DATE: advent_of_code::AocDate =
+            advent_of_code::AocDate::new(advent_of_code::year!($year), advent_of_code::day!($day));
+
         /// The current day.
-        const DAY: advent_of_code::Day = advent_of_code::day!($day);
+        #[allow(dead_code)]
+        const DAY: advent_of_code::Day = DATE.day;
+
+        /// The registry entry for the current day.
+        pub const SOLUTION: advent_of_code::template::Solution =
+            advent_of_code::template::Solution {
+                date: DATE,
+                runner: $runner,
+                timeout: advent_of_code::solution!(@timeout [$($timeout)?]),
+            };
 
+        // `main` is unused when the solution is compiled into the registry of the main binary.
+        #[allow(dead_code)]
         fn main() {
+            use advent_of_code::template::input::InputSource;
             use advent_of_code::template::runner::*;
-            let input = advent_of_code::template::read_file("inputs", DAY);
-            run_part(part_one, &input, DAY, 1);
-            run_part(part_two, &input, DAY, 2);
+            let source = InputSource::from_env();
+            let options = RunOptions::from_env();
+            if options.submit.is_some() && !source.is_puzzle() {
+                eprintln!("--submit can only be used with the puzzle input.");
+                std::process::exit(1);
+            }
+            let input = source.read(DATE).unwrap_or_else(|e| {
+                eprintln!("{e}");
+                std::process::exit(1);
+            });
+            let results = SOLUTION.run(&input, &options);
+            advent_of_code::template::output::print_results(options.format, &results);
         }
     };
 }
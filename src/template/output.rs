@@ -0,0 +1,307 @@
+/// Machine-readable output of solution results.
+use std::error::Error;
+use std::fmt::{Display, Write};
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::template::runner::PartResult;
+use crate::template::stats::BenchStats;
+
+/// The format that solution results are printed in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Decorated text for the terminal.
+    #[default]
+    Human,
+    /// A single JSON array with one object per part, printed once all parts ran.
+    Json,
+    /// One JSON object per part, printed as soon as the part ran.
+    JsonLines,
+}
+
+impl OutputFormat {
+    #[must_use]
+    pub fn is_human(self) -> bool {
+        self == Self::Human
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = OutputFormatFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(Self::Human),
+            "json" => Ok(Self::Json),
+            "jsonl" | "json-lines" => Ok(Self::JsonLines),
+            _ => Err(OutputFormatFromStrError),
+        }
+    }
+}
+
+/// An error which can be returned when parsing an [`OutputFormat`].
+#[derive(Debug)]
+pub struct OutputFormatFromStrError;
+
+impl Error for OutputFormatFromStrError {}
+
+impl Display for OutputFormatFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting one of `human`, `json` or `jsonl`")
+    }
+}
+
+/// Serializes a part result to a single-line JSON object.
+#[must_use]
+pub fn to_json(result: &PartResult) -> String {
+    let answer = result
+        .answer
+        .solved()
+        .map_or_else(|| "null".into(), |answer| json_string(answer));
+
+    let stats = result
+        .stats
+        .as_ref()
+        .map_or_else(|| "null".into(), stats_to_json);
+
+    let error = result
+        .answer
+        .error()
+        .map_or_else(|| "null".into(), json_string);
+
+    let parse = result
+        .parse
+        .map_or_else(|| "null".into(), |duration| duration.as_nanos().to_string());
+
+    format!(
+        r#"{{"year":{},"day":{},"part":{},"answer":{},"duration_ns":{},"samples":{},"stats":{},"status":"{}","error":{},"parse_ns":{}}}"#,
+        result.date.year,
+        result.date.day.into_inner(),
+        result.part,
+        answer,
+        result.duration.as_nanos(),
+        result.samples,
+        stats,
+        result.status(),
+        error,
+        parse
+    )
+}
+
+fn stats_to_json(stats: &BenchStats) -> String {
+    format!(
+        r#"{{"min_ns":{},"median_ns":{},"p95_ns":{},"max_ns":{},"mean_ns":{},"std_dev_ns":{},"outliers":{}}}"#,
+        stats.min.as_nanos(),
+        stats.median.as_nanos(),
+        stats.p95.as_nanos(),
+        stats.max.as_nanos(),
+        stats.mean.as_nanos(),
+        stats.std_dev.as_nanos(),
+        stats.outliers
+    )
+}
+
+/// Prints the results that were not streamed while running, i.e. the JSON array for [`OutputFormat::Json`].
+pub fn print_results(format: OutputFormat, results: &[PartResult]) {
+    if format == OutputFormat::Json {
+        let items: Vec<String> = results.iter().map(to_json).collect();
+        println!("[{}]", items.join(","));
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len() + 2);
+    escaped.push('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(escaped, "\\u{:04x}", c as u32);
+            }
+            c => escaped.push(c),
+        }
+    }
+
+    escaped.push('"');
+    escaped
+}
+
+/// The answer of a part, read back from an object that was serialized by [`to_json`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonAnswer {
+    pub part: u8,
+    pub answer: Option<String>,
+    pub duration: Duration,
+}
+
+/// Reads the part, answer and duration from a single-line JSON object serialized by [`to_json`].
+/// This is not a general JSON parser, it relies on the field order of [`to_json`].
+#[must_use]
+pub fn answer_from_json(line: &str) -> Option<JsonAnswer> {
+    let (_, rest) = line.split_once(r#""part":"#)?;
+    let (part, rest) = rest.split_once(',')?;
+    let rest = rest.strip_prefix(r#""answer":"#)?;
+
+    let (answer, rest) = match rest.strip_prefix("null") {
+        Some(rest) => (None, rest),
+        None => {
+            let (answer, rest) = parse_json_string(rest)?;
+            (Some(answer), rest)
+        }
+    };
+
+    let (_, rest) = rest.split_once(r#""duration_ns":"#)?;
+    let (duration, _) = rest.split_once(',')?;
+
+    Some(JsonAnswer {
+        part: part.parse().ok()?,
+        answer,
+        duration: Duration::from_nanos(duration.parse().ok()?),
+    })
+}
+
+/// Parses a JSON string literal at the start of `s`, returns its value and the remaining input.
+fn parse_json_string(s: &str) -> Option<(String, &str)> {
+    let mut chars = s.strip_prefix('"')?.char_indices();
+    let mut value = String::new();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Some((value, &s[i + 2..])),
+            '\\' => match chars.next()?.1 {
+                'n' => value.push('\n'),
+                'r' => value.push('\r'),
+                't' => value.push('\t'),
+                'u' => {
+                    let code: String = chars.by_ref().take(4).map(|(_, c)| c).collect();
+                    value.push(char::from_u32(u32::from_str_radix(&code, 16).ok()?)?);
+                }
+                c => value.push(c),
+            },
+            c => value.push(c),
+        }
+    }
+
+    None
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{answer_from_json, to_json, JsonAnswer, OutputFormat};
+    use crate::template::runner::{Answer, PartResult};
+    use crate::template::stats::BenchStats;
+    use crate::{day, year, AocDate, Day};
+
+    fn result(day: Day, part: u8, answer: Answer<String>, duration_nanos: u64) -> PartResult {
+        PartResult {
+            duration: Duration::from_nanos(duration_nanos),
+            ..PartResult::for_test(AocDate::new(year!(2023), day), part, answer)
+        }
+    }
+
+    #[test]
+    fn parses_formats() {
+        assert_eq!(
+            "human".parse::<OutputFormat>().unwrap(),
+            OutputFormat::Human
+        );
+        assert_eq!("json".parse::<OutputFormat>().unwrap(), OutputFormat::Json);
+        assert_eq!(
+            "jsonl".parse::<OutputFormat>().unwrap(),
+            OutputFormat::JsonLines
+        );
+        assert!("yaml".parse::<OutputFormat>().is_err());
+    }
+
+    #[test]
+    fn serializes_solved_part() {
+        let result = result(day!(5), 2, Answer::Solved("46".into()), 74130);
+        assert_eq!(
+            to_json(&result),
+            r#"{"year":2023,"day":5,"part":2,"answer":"46","duration_ns":74130,"samples":1,"stats":null,"status":"solved","error":null,"parse_ns":null}"#
+        );
+    }
+
+    #[test]
+    fn serializes_benched_part() {
+        let stats = BenchStats::from_samples(&[
+            Duration::from_nanos(10),
+            Duration::from_nanos(20),
+            Duration::from_nanos(30),
+        ])
+        .unwrap();
+        let result = PartResult {
+            duration: stats.median,
+            samples: stats.samples,
+            stats: Some(stats),
+            ..result(day!(5), 1, Answer::Solved("35".into()), 0)
+        };
+        assert_eq!(
+            to_json(&result),
+            r#"{"year":2023,"day":5,"part":1,"answer":"35","duration_ns":20,"samples":3,"stats":{"min_ns":10,"median_ns":20,"p95_ns":30,"max_ns":30,"mean_ns":20,"std_dev_ns":8,"outliers":0},"status":"solved","error":null,"parse_ns":null}"#
+        );
+    }
+
+    #[test]
+    fn serializes_unsolved_part() {
+        let result = result(day!(7), 2, Answer::NotImplemented, 10);
+        assert_eq!(
+            to_json(&result),
+            r#"{"year":2023,"day":7,"part":2,"answer":null,"duration_ns":10,"samples":1,"stats":null,"status":"unsolved","error":null,"parse_ns":null}"#
+        );
+    }
+
+    #[test]
+    fn serializes_failed_part() {
+        let result = result(
+            day!(2),
+            1,
+            Answer::Error("line 3, column 9: expected a number, found `x`".into()),
+            10,
+        );
+        assert_eq!(
+            to_json(&result),
+            r#"{"year":2023,"day":2,"part":1,"answer":null,"duration_ns":10,"samples":1,"stats":null,"status":"error","error":"line 3, column 9: expected a number, found `x`","parse_ns":null}"#
+        );
+    }
+
+    #[test]
+    fn serializes_parse_time() {
+        let result = PartResult {
+            parse: Some(Duration::from_nanos(3400)),
+            ..result(day!(4), 1, Answer::Solved("13".into()), 120)
+        };
+        assert!(to_json(&result).ends_with(r#","parse_ns":3400}"#));
+    }
+
+    #[test]
+    fn escapes_answers() {
+        let result = result(day!(1), 1, Answer::Solved("#.\"\\\n.#".into()), 1);
+        assert!(to_json(&result).contains(r##""answer":"#.\"\\\n.#""##));
+    }
+
+    #[test]
+    fn reads_answers_back() {
+        for answer in [Some("46"), Some("#.\"\\\n.#\u{1}"), None] {
+            let result = result(day!(5), 2, answer.map(Into::into).into(), 74130);
+
+            assert_eq!(
+                answer_from_json(&to_json(&result)),
+                Some(JsonAnswer {
+                    part: 2,
+                    answer: answer.map(Into::into),
+                    duration: Duration::from_nanos(74130),
+                })
+            );
+        }
+
+        assert_eq!(answer_from_json("Part 1: 42"), None);
+    }
+}
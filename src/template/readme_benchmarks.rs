@@ -1,8 +1,10 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
+use std::time::Duration;
 use std::{fs, io};
 
-use crate::Day;
+use crate::template::stats::BenchStats;
+use crate::AocDate;
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -20,9 +22,14 @@ impl From<std::io::Error> for Error {
 
 #[derive(Clone)]
 pub struct Timings {
-    pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
+    pub date: AocDate,
+    /// The median execution time of the parse stage, if the solution has one.
+    pub parse: Option<Duration>,
+    pub part_1: Option<BenchStats>,
+    pub part_2: Option<BenchStats>,
+    /// Whether each part ran out of its time budget.
+    pub timed_out: [bool; 2],
+    /// The sum of the median execution times of the parse stage and both parts.
     pub total_nanos: f64,
 }
 
@@ -32,8 +39,8 @@ pub struct TablePosition {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(date: AocDate) -> String {
+    format!("./src/bin/{}-{}.rs", date.year, date.day)
 }
 
 fn locate_table(readme: &str) -> Result<TablePosition, Error> {
@@ -65,18 +72,21 @@ fn construct_table(prefix: &str, timings: Vec<Timings>, total_millis: f64) -> St
         MARKER.into(),
         header,
         String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+        "| Day | Parse | Part 1 | Part 2 |".into(),
+        "| :---: | :---: | :---: | :---:  |".into(),
     ];
 
     for timing in timings {
-        let path = get_path_for_bin(timing.day);
+        let path = get_path_for_bin(timing.date);
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
+            "| [Day {}]({}) | `{}` | `{}` | `{}` |",
+            timing.date.day.into_inner(),
             path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            timing
+                .parse
+                .map_or_else(|| "-".into(), |duration| format!("{duration:.1?}")),
+            format_part(timing.part_1.as_ref(), timing.timed_out[0]),
+            format_part(timing.part_2.as_ref(), timing.timed_out[1])
         ));
     }
 
@@ -87,6 +97,14 @@ fn construct_table(prefix: &str, timings: Vec<Timings>, total_millis: f64) -> St
     lines.join("\n")
 }
 
+fn format_part(stats: Option<&BenchStats>, timed_out: bool) -> String {
+    if timed_out {
+        return "timed out".into();
+    }
+
+    stats.map_or_else(|| "-".into(), |stats| format!("{:.1?}", stats.median))
+}
+
 fn update_content(s: &mut String, timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
     let positions = locate_table(s)?;
     let table = construct_table("##", timings, total_millis);
@@ -104,27 +122,44 @@ pub fn update(timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
 
 #[cfg(feature = "test_lib")]
 mod tests {
+    use std::time::Duration;
+
     use super::{update_content, Timings, MARKER};
-    use crate::day;
+    use crate::template::stats::BenchStats;
+    use crate::{day, year, AocDate};
+
+    fn get_mock_stats(median_millis: u64) -> Option<BenchStats> {
+        BenchStats::from_samples(&[
+            Duration::from_millis(median_millis - 1),
+            Duration::from_millis(median_millis),
+            Duration::from_millis(median_millis * 10),
+        ])
+    }
 
     fn get_mock_timings() -> Vec<Timings> {
         vec![
             Timings {
-                day: day!(1),
-                part_1: Some("10ms".into()),
-                part_2: Some("20ms".into()),
+                date: AocDate::new(year!(2023), day!(1)),
+                parse: None,
+                part_1: get_mock_stats(10),
+                part_2: get_mock_stats(20),
+                timed_out: [false; 2],
                 total_nanos: 3e+10,
             },
             Timings {
-                day: day!(2),
-                part_1: Some("30ms".into()),
-                part_2: Some("40ms".into()),
+                date: AocDate::new(year!(2023), day!(2)),
+                parse: None,
+                part_1: get_mock_stats(30),
+                part_2: get_mock_stats(40),
+                timed_out: [false; 2],
                 total_nanos: 7e+10,
             },
             Timings {
-                day: day!(4),
-                part_1: Some("40ms".into()),
-                part_2: Some("50ms".into()),
+                date: AocDate::new(year!(2023), day!(4)),
+                parse: Some(Duration::from_micros(1500)),
+                part_1: get_mock_stats(40),
+                part_2: None,
+                timed_out: [false, true],
                 total_nanos: 9e+10,
             },
         ]
@@ -170,11 +205,11 @@ mod tests {
             "<!--- benchmarking table --->",
             "## Benchmarks",
             "",
-            "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| Day | Parse | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/2023-01.rs) | `-` | `10.0ms` | `20.0ms` |",
+            "| [Day 2](./src/bin/2023-02.rs) | `-` | `30.0ms` | `40.0ms` |",
+            "| [Day 4](./src/bin/2023-04.rs) | `1.5ms` | `40.0ms` | `timed out` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
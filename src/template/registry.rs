@@ -0,0 +1,115 @@
+/// Registry of the solutions that are compiled into the main binary.
+/// Every day registers itself through the `solution!` macro, the main binary collects these entries at build time.
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Duration;
+
+use crate::template::runner::{PartResult, RunOptions};
+use crate::template::timeout;
+use crate::{AocDate, Year};
+
+/// Runs both parts of a solution against an input, after its parse stage if it has one.
+pub type SolutionRunner = fn(&str, &RunOptions) -> [PartResult; 2];
+
+/// A solved day of advent, as registered by the `solution!` macro.
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub date: AocDate,
+    pub runner: SolutionRunner,
+    /// The time budget of each part, overrides the budget of the command.
+    pub timeout: Option<Duration>,
+}
+
+impl Solution {
+    /// Runs both parts of the solution against the given input. With a time budget, parts that exceed it
+    /// are reported as timed out.
+    pub fn run(&self, input: &str, options: &RunOptions) -> [PartResult; 2] {
+        match self.timeout.or(options.timeout) {
+            Some(budget) => timeout::run(self.runner, self.date, input, options, budget),
+            None => (self.runner)(input, options),
+        }
+    }
+
+    /// Like [`Solution::run`], but returns [`None`] if the solution panicked, so that commands running
+    /// several days can report the day as failed and continue. The panic message is printed by the panic hook.
+    pub fn try_run(&self, input: &str, options: &RunOptions) -> Option<[PartResult; 2]> {
+        panic::catch_unwind(AssertUnwindSafe(|| self.run(input, options))).ok()
+    }
+}
+
+/// A lookup table of all registered solutions.
+#[derive(Clone, Copy)]
+pub struct Registry {
+    solutions: &'static [Solution],
+}
+
+impl Registry {
+    #[must_use]
+    pub const fn new(solutions: &'static [Solution]) -> Self {
+        Self { solutions }
+    }
+
+    /// Returns the solution for a day, if that day is solved.
+    #[must_use]
+    pub fn get(&self, date: AocDate) -> Option<&Solution> {
+        self.solutions.iter().find(|solution| solution.date == date)
+    }
+
+    /// Returns an iterator over all registered solutions of a year.
+    pub fn iter(&self, year: Year) -> impl Iterator<Item = &Solution> {
+        self.solutions
+            .iter()
+            .filter(move |solution| solution.date.year == year)
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Solution;
+    use crate::template::runner::{run_part, Answer, PartResult, RunOptions};
+    use crate::{day, year, AocDate};
+
+    const DATE: AocDate = AocDate::new(year!(2023), day!(1));
+
+    fn solution(runner: fn(&str, &RunOptions) -> [PartResult; 2]) -> Solution {
+        Solution {
+            date: DATE,
+            runner,
+            timeout: None,
+        }
+    }
+
+    fn panics_in_part_two(input: &str, options: &RunOptions) -> [PartResult; 2] {
+        [
+            run_part(|input: &str| Some(input.len()), input, DATE, 1, options),
+            run_part(
+                |_: &str| -> Option<u32> { panic!("oops") },
+                input,
+                DATE,
+                2,
+                options,
+            ),
+        ]
+    }
+
+    #[test]
+    fn reports_panicking_solutions() {
+        let options = RunOptions {
+            quiet: true,
+            ..RunOptions::default()
+        };
+
+        assert!(solution(panics_in_part_two)
+            .try_run("abc", &options)
+            .is_none());
+
+        let [one, _] = solution(|input, options| {
+            [
+                run_part(|input: &str| Some(input.len()), input, DATE, 1, options),
+                run_part(|_: &str| None::<u32>, input, DATE, 2, options),
+            ]
+        })
+        .try_run("abc", &options)
+        .unwrap();
+        assert_eq!(one.answer, Answer::Solved("3".into()));
+    }
+}
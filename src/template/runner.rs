@@ -1,67 +1,545 @@
+use crate::parse::ParseError;
+use crate::template::aoc_backend::AocBackend;
+use crate::template::capture::{errln, out, outln};
 /// Encapsulates code that interacts with solution functions.
-use crate::template::{aoc_cli, ANSI_ITALIC, ANSI_RESET};
-use crate::Day;
+use crate::template::output::{self, OutputFormat};
+use crate::template::stats::BenchStats;
+use crate::template::submissions::{self, SubmissionOutcome};
+use crate::template::timeout::{self, Stage};
+use crate::template::{answers, ANSI_ITALIC, ANSI_RESET};
+use crate::AocDate;
 use std::fmt::Display;
 use std::io::{stdout, Write};
-use std::process::Output;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, process};
 
 use super::ANSI_BOLD;
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// Options that control how solution parts are run.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RunOptions {
+    /// Bench the solution parts instead of running them once.
+    pub time: bool,
+    /// Submit the result of this part via aoc-cli.
+    pub submit: Option<u8>,
+    /// The format results are printed in.
+    pub format: OutputFormat,
+    /// Don't print results while running, the caller reports them.
+    pub quiet: bool,
+    /// The time budget of each part, unless the solution sets its own.
+    pub timeout: Option<Duration>,
+    /// Only run this part, the other one is skipped. Used to run part two on its own after part one timed out.
+    pub part: Option<u8>,
+}
+
+impl RunOptions {
+    /// Reads the options from the command-line arguments of the current process.
+    #[must_use]
+    pub fn from_env() -> Self {
+        let mut args = pico_args::Arguments::from_env();
+
+        let Ok(submit) = args.opt_value_from_str("--submit") else {
+            eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
+            process::exit(1);
+        };
+
+        let format = match args.opt_value_from_str("--format") {
+            Ok(format) => format.unwrap_or_default(),
+            Err(e) => {
+                eprintln!("Unexpected command-line input: {e}");
+                process::exit(1);
+            }
+        };
+
+        let timeout = match args.opt_value_from_fn("--timeout", timeout::parse_duration) {
+            Ok(timeout) => timeout,
+            Err(e) => {
+                eprintln!("Unexpected command-line input: {e}");
+                process::exit(1);
+            }
+        };
+
+        Self {
+            time: args.contains("--time"),
+            submit,
+            format,
+            quiet: false,
+            timeout,
+            part: None,
+        }
+    }
+}
+
+/// The outcome of running a single solution part.
+#[derive(Debug, Clone)]
+pub struct PartResult {
+    pub date: AocDate,
+    pub part: u8,
+    pub answer: Answer<String>,
+    /// The execution time, or the median execution time if the part was benched.
+    pub duration: Duration,
+    pub samples: u128,
+    /// Statistics over all samples if the part was benched.
+    pub stats: Option<BenchStats>,
+    /// The (median) execution time of the parse stage that both parts share, if the solution has one.
+    pub parse: Option<Duration>,
+}
+
+impl PartResult {
+    /// A short machine-readable description of the outcome.
+    #[must_use]
+    pub fn status(&self) -> &'static str {
+        self.answer.status()
+    }
+
+    /// A result of a single run without statistics or parse stage, tests override the fields they need.
+    #[cfg(feature = "test_lib")]
+    #[must_use]
+    pub fn for_test(date: AocDate, part: u8, answer: Answer<String>) -> Self {
+        placeholder(date, part, answer, Duration::ZERO, None)
+    }
+}
+
+/// The answer of a solution part, or the reason there is none.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer<T> {
+    Solved(T),
+    /// The part is not implemented yet.
+    NotImplemented,
+    /// The part is implemented, but the input has no answer.
+    NoAnswer,
+    /// The part failed, e.g. on a malformed input.
+    Error(String),
+    /// The part ran out of its time budget and was abandoned.
+    TimedOut,
+}
+
+impl<T> Answer<T> {
+    /// Returns the answer if the part was solved.
+    #[must_use]
+    pub fn solved(&self) -> Option<&T> {
+        match self {
+            Self::Solved(answer) => Some(answer),
+            _ => None,
+        }
+    }
+
+    /// Returns the error message if the part failed.
+    #[must_use]
+    pub fn error(&self) -> Option<&str> {
+        match self {
+            Self::Error(message) => Some(message),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn as_ref(&self) -> Answer<&T> {
+        match self {
+            Self::Solved(answer) => Answer::Solved(answer),
+            Self::NotImplemented => Answer::NotImplemented,
+            Self::NoAnswer => Answer::NoAnswer,
+            Self::Error(message) => Answer::Error(message.clone()),
+            Self::TimedOut => Answer::TimedOut,
+        }
+    }
+
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Answer<U> {
+        match self {
+            Self::Solved(answer) => Answer::Solved(f(answer)),
+            Self::NotImplemented => Answer::NotImplemented,
+            Self::NoAnswer => Answer::NoAnswer,
+            Self::Error(message) => Answer::Error(message),
+            Self::TimedOut => Answer::TimedOut,
+        }
+    }
+
+    /// Attaches lines that are printed below the answer, e.g. intermediate values.
+    #[must_use]
+    pub fn with_details(self, details: impl IntoIterator<Item = impl Into<String>>) -> Detailed<T> {
+        Detailed {
+            answer: self,
+            details: details.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// A short machine-readable description of the answer.
+    #[must_use]
+    pub fn status(&self) -> &'static str {
+        match self {
+            Self::Solved(_) => "solved",
+            Self::NotImplemented => "unsolved",
+            Self::NoAnswer => "no_answer",
+            Self::Error(_) => "error",
+            Self::TimedOut => "timed_out",
+        }
+    }
+}
+
+/// [`None`] is a part that is not implemented yet, like in the scaffolded template.
+impl<T> From<Option<T>> for Answer<T> {
+    fn from(answer: Option<T>) -> Self {
+        answer.map_or(Self::NotImplemented, Self::Solved)
+    }
+}
+
+/// An answer with extra lines that are printed below it, e.g. intermediate values or a rendering of the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Detailed<T> {
+    pub answer: Answer<T>,
+    pub details: Vec<String>,
+}
+
+/// An error that a solution part can return.
+pub trait PartError: Display {
+    /// The diagnostic printed to stderr when the part fails, by default the error message.
+    fn diagnostic(&self, _input: &str) -> String {
+        format!("error: {self}")
+    }
+}
+
+/// Points at the malformed part of the input.
+impl PartError for ParseError {
+    fn diagnostic(&self, input: &str) -> String {
+        ParseError::diagnostic(self, input)
+    }
+}
+
+impl PartError for String {}
+
+impl PartError for &str {}
+
+impl PartError for Box<dyn std::error::Error> {}
+
+/// A value that a solution part can return: an [`Option`], a [`Result`], an [`Answer`] or a [`Detailed`] answer.
+pub trait PartOutput {
+    type Value: Display;
+
+    /// Returns the answer, or the reason there is none.
+    fn answer(&self) -> Answer<&Self::Value>;
+
+    /// Extra lines printed below the answer. For failed parts, they are printed to stderr.
+    fn details(&self, _input: &str) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+impl<T: Display> PartOutput for Option<T> {
+    type Value = T;
+
+    fn answer(&self) -> Answer<&T> {
+        self.as_ref().into()
+    }
+}
+
+impl<T: Display, E: PartError> PartOutput for Result<T, E> {
+    type Value = T;
+
+    fn answer(&self) -> Answer<&T> {
+        match self {
+            Ok(answer) => Answer::Solved(answer),
+            Err(error) => Answer::Error(error.to_string()),
+        }
+    }
+
+    fn details(&self, input: &str) -> Vec<String> {
+        match self {
+            Ok(_) => Vec::new(),
+            Err(error) => error.diagnostic(input).lines().map(Into::into).collect(),
+        }
+    }
+}
+
+impl<T: Display> PartOutput for Answer<T> {
+    type Value = T;
+
+    fn answer(&self) -> Answer<&T> {
+        self.as_ref()
+    }
+}
+
+impl<T: Display> PartOutput for Detailed<T> {
+    type Value = T;
+
+    fn answer(&self) -> Answer<&T> {
+        self.answer.as_ref()
+    }
+
+    fn details(&self, _input: &str) -> Vec<String> {
+        self.details.clone()
+    }
+}
+
+pub fn run_part<I: Clone + AsRef<str>, R: PartOutput>(
+    func: impl Fn(I) -> R,
+    input: I,
+    date: AocDate,
+    part: u8,
+    options: &RunOptions,
+) -> PartResult {
+    let text = input.clone();
+    run_part_on(func, input, text.as_ref(), None, date, part, options)
+}
+
+/// Parses the input once and runs both parts on the parsed input. The parse stage is timed (and benched) on its own,
+/// if it fails, both parts fail with its error.
+pub fn run_parsed<'a, P, E: PartError, R1: PartOutput, R2: PartOutput>(
+    parse: impl Fn(&'a str) -> Result<P, E>,
+    part_one: impl Fn(&P) -> R1,
+    part_two: impl Fn(&P) -> R2,
+    input: &'a str,
+    date: AocDate,
+    options: &RunOptions,
+) -> [PartResult; 2] {
+    let is_human = options.format.is_human() && !options.quiet;
+
+    let background = running_in_background(options);
+
+    timeout::started(Stage::Parse);
+
+    let (parsed, base_time, stats) = run_timed(&parse, input, options.time, is_human, |parsed| {
+        if is_human && !timeout::is_cancelled() {
+            print_parse_result(parsed.is_ok(), "");
+        }
+    });
+
+    // the parts were reported as timed out already.
+    if timeout::is_cancelled() {
+        return [1, 2].map(|part| placeholder(date, part, Answer::TimedOut, base_time, None));
+    }
+
+    let (duration, samples) = stats.map_or((base_time, 1), |stats| (stats.median, stats.samples));
+
+    if is_human {
+        print_parse_result(parsed.is_ok(), &format_duration(&duration, samples));
+
+        if let (Some(stats), true) = (&stats, parsed.is_ok()) {
+            outln!("{}", format_stats(stats));
+        }
+    }
+
+    warn_if_skewed(stats.as_ref(), background, options);
+
+    match parsed {
+        Ok(parsed) => [
+            run_part_on(&part_one, &parsed, input, Some(duration), date, 1, options),
+            run_part_on(&part_two, &parsed, input, Some(duration), date, 2, options),
+        ],
+        Err(error) => {
+            if !options.quiet {
+                errln!("{}", error.diagnostic(input));
+            }
+
+            [1, 2].map(|part| {
+                let answer = Answer::Error(error.to_string());
+                let result = placeholder(date, part, answer, Duration::ZERO, Some(duration));
+
+                if options.part.map_or(true, |only| only == part) {
+                    report(&result, options);
+                    timeout::finished(&result);
+                }
+
+                result
+            })
+        }
+    }
+}
+
+/// Runs a part on an input, `text` is the puzzle input that diagnostics point at.
+fn run_part_on<I: Clone, R: PartOutput>(
+    func: impl Fn(I) -> R,
+    input: I,
+    text: &str,
+    parse: Option<Duration>,
+    date: AocDate,
+    part: u8,
+    options: &RunOptions,
+) -> PartResult {
+    if options.part.is_some_and(|only| only != part) {
+        return placeholder(date, part, Answer::NotImplemented, Duration::ZERO, parse);
+    }
+
     let part_str = format!("Part {part}");
+    let is_human = options.format.is_human() && !options.quiet;
+
+    let background = running_in_background(options);
+
+    timeout::started(Stage::Part(part));
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let (result, base_time, stats) =
+        run_timed(func, input.clone(), options.time, is_human, |result| {
+            if is_human && !timeout::is_cancelled() {
+                print_result(&result.answer(), &part_str, "");
+            }
+        });
+
+    // the part was reported as timed out already.
+    if timeout::is_cancelled() {
+        return placeholder(date, part, Answer::TimedOut, base_time, parse);
+    }
+
+    let (duration, samples) = stats.map_or((base_time, 1), |stats| (stats.median, stats.samples));
+    let answer = result.answer();
+    let details = result.details(text);
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    if is_human {
+        print_result(&answer, &part_str, &format_duration(&duration, samples));
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+        if let (Some(stats), Some(_)) = (&stats, answer.solved()) {
+            outln!("{}", format_stats(stats));
+        }
     }
+
+    warn_if_skewed(stats.as_ref(), background, options);
+
+    if let Answer::Error(message) = &answer {
+        // diagnostics go to stderr in every format, so they don't break machine-readable output.
+        if !options.quiet && details.is_empty() {
+            errln!("error: {message}");
+        } else if !options.quiet {
+            details.iter().for_each(|line| errln!("{line}"));
+        }
+    } else if is_human {
+        details.iter().for_each(|line| outln!("{line}"));
+    }
+
+    let answer = answer.map(ToString::to_string);
+
+    if let Some(answer) = answer.solved() {
+        submit_result(answer, date, part, options);
+    }
+
+    let result = PartResult {
+        date,
+        part,
+        answer,
+        duration,
+        samples,
+        stats,
+        parse,
+    };
+
+    if options.format == OutputFormat::JsonLines && !options.quiet {
+        outln!("{}", output::to_json(&result));
+    }
+
+    timeout::finished(&result);
+
+    result
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+/// The number of parts that timed out earlier and still run in the background, if this run is benched.
+fn running_in_background(options: &RunOptions) -> usize {
+    if options.time {
+        timeout::running_in_background()
+    } else {
+        0
+    }
+}
+
+/// Warns that a benchmark shared the CPU with parts that timed out and kept running in the background.
+fn warn_if_skewed(stats: Option<&BenchStats>, background: usize, options: &RunOptions) {
+    if stats.is_some() && background > 0 && !options.quiet {
+        errln!(
+            "warning: {background} part(s) that timed out kept running in the background during this benchmark, its timings may be skewed."
+        );
+    }
+}
+
+/// The result of a part that didn't run to completion, e.g. because the parse stage failed.
+pub(crate) fn placeholder(
+    date: AocDate,
+    part: u8,
+    answer: Answer<String>,
+    duration: Duration,
+    parse: Option<Duration>,
+) -> PartResult {
+    PartResult {
+        date,
+        part,
+        answer,
+        duration,
+        samples: 1,
+        stats: None,
+        parse,
+    }
+}
+
+/// Prints a result that wasn't printed while running, like a part that timed out.
+pub(crate) fn report(result: &PartResult, options: &RunOptions) {
+    if options.quiet {
+        return;
+    }
+
+    if options.format.is_human() {
+        let duration = format!(" (after {:.1?})", result.duration);
+        print_result(&result.answer, &format!("Part {}", result.part), &duration);
+    } else if options.format == OutputFormat::JsonLines {
+        outln!("{}", output::to_json(result));
+    }
+}
+
+/// Run a solution part. The behavior differs depending on whether the part is timed:
+///  1. if not, the function is executed once.
+///  2. if it is, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
 fn run_timed<I: Clone, T>(
     func: impl Fn(I) -> T,
     input: I,
+    is_timed: bool,
+    show_progress: bool,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> (T, Duration, Option<BenchStats>) {
     let timer = Instant::now();
     let result = func(input.clone());
     let base_time = timer.elapsed();
 
+    // only the first run counts towards the time budget, not the benchmark.
+    timeout::disarm();
+
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    let stats = if is_timed && !timeout::is_cancelled() {
+        bench(func, input, &base_time, show_progress)
     } else {
-        (base_time, 1)
+        None
     };
 
-    (result, run.0, run.1)
+    (result, base_time, stats)
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+/// Bench a solution part. Warm-up iterations (approx. 100 milliseconds of execution time, at most 1000)
+/// are run first to fill caches and let the CPU clock up, their timings are discarded.
+fn bench<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    show_progress: bool,
+) -> Option<BenchStats> {
+    if show_progress {
+        out!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+        let _ = stdout().flush();
+    }
+
+    let base_nanos = cmp::max(base_time.as_nanos(), 10);
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+    let warmup_iterations = (Duration::from_millis(100).as_nanos() / base_nanos).clamp(1, 1000);
+    let bench_iterations = (Duration::from_secs(1).as_nanos() / base_nanos).clamp(10, 10000);
 
-    let bench_iterations = cmp::min(
-        10000,
-        cmp::max(
-            Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10),
-            10,
-        ),
-    );
+    for _ in 0..warmup_iterations {
+        if timeout::is_cancelled() {
+            return None;
+        }
+
+        func(input.clone());
+    }
 
     let mut timers: Vec<Duration> = vec![];
 
     for _ in 0..bench_iterations {
+        if timeout::is_cancelled() {
+            return None;
+        }
+
         // need a clone here to make the borrow checker happy.
         let cloned = input.clone();
         let timer = Instant::now();
@@ -69,19 +547,7 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
-}
-
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+    BenchStats::from_samples(&timers)
 }
 
 fn format_duration(duration: &Duration, samples: u128) -> String {
@@ -92,76 +558,217 @@ fn format_duration(duration: &Duration, samples: u128) -> String {
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn format_stats(stats: &BenchStats) -> String {
+    format!(
+        "  {ANSI_ITALIC}min {:.1?} · median {:.1?} · p95 {:.1?} · max {:.1?} · mean {:.1?} ± {:.1?} ({} outliers){ANSI_RESET}",
+        stats.min, stats.median, stats.p95, stats.max, stats.mean, stats.std_dev, stats.outliers
+    )
+}
+
+fn print_result<T: Display>(result: &Answer<T>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
-        Some(result) => {
+        Answer::Solved(result) => {
             if result.to_string().contains('\n') {
                 let str = format!("{part}: ▼ {duration_str}");
                 if is_intermediate_result {
-                    print!("{str}");
+                    out!("{str}");
                 } else {
-                    print!("\r");
-                    println!("{str}");
-                    println!("{result}");
+                    out!("\r");
+                    outln!("{str}");
+                    outln!("{result}");
                 }
             } else {
                 let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
                 if is_intermediate_result {
-                    print!("{str}");
+                    out!("{str}");
                 } else {
-                    print!("\r");
-                    println!("{str}");
+                    out!("\r");
+                    outln!("{str}");
                 }
             }
         }
-        None => {
+        Answer::NoAnswer => {
             if is_intermediate_result {
-                print!("{part}: ✖");
+                out!("{part}: ∅ no answer");
             } else {
-                print!("\r");
-                println!("{part}: ✖             ");
+                out!("\r");
+                outln!("{part}: ∅ no answer{duration_str}");
+            }
+        }
+        Answer::TimedOut => {
+            if is_intermediate_result {
+                out!("{part}: ⏱ timed out");
+            } else {
+                out!("\r");
+                outln!("{part}: ⏱ timed out{duration_str}             ");
+            }
+        }
+        Answer::NotImplemented | Answer::Error(_) => {
+            if is_intermediate_result {
+                out!("{part}: ✖");
+            } else {
+                out!("\r");
+                outln!("{part}: ✖             ");
             }
         }
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
+fn print_parse_result(is_ok: bool, duration_str: &str) {
+    let mark = if is_ok { "✔" } else { "✖" };
 
-    if !args.contains(&"--submit".into()) {
+    if duration_str.is_empty() {
+        out!("Parse: {mark}");
+    } else {
+        out!("\r");
+        outln!("Parse: {mark}{duration_str}             ");
+    }
+}
+
+/// Try to submit one part of the solution if:
+///  1. `--submit` was passed for this part.
+///  2. the answer is not known to be wrong and the part was not solved yet, according to the submission log.
+///  3. a session cookie is configured or aoc-cli is installed.
+///
+/// The outcome is appended to the submission log. Correct answers are also stored as accepted answers.
+fn submit_result(
+    result: &str,
+    date: AocDate,
+    part: u8,
+    options: &RunOptions,
+) -> Option<SubmissionOutcome> {
+    if options.submit != Some(part) {
         return None;
     }
 
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    let log = submissions::read(date).unwrap_or_else(|e| {
+        eprintln!("could not read submission log: {e}");
+        vec![]
+    });
+
+    let check = submissions::check(&log, part, result);
+
+    if check.is_refusal() {
+        eprintln!("Not submitting, {check}");
+        return None;
+    } else if check != submissions::Check::Ok {
+        eprintln!("Warning: {check}");
     }
 
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
+    let backend = match AocBackend::detect() {
+        Ok(backend) => backend,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    print_status(options, &format!("Submitting result via {backend}..."));
 
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    let response = match backend.submit(date, part, result) {
+        Ok(response) => response,
+        Err(e) => {
+            eprintln!("failed to submit via {backend}: {e}");
+            return None;
+        }
     };
 
-    if part_submit != part {
+    print_status(options, response.message.trim_end());
+
+    let Some(outcome) = SubmissionOutcome::from_message(&response.message) else {
+        eprintln!("could not interpret the response, it was not logged.");
         return None;
+    };
+
+    if let Err(e) = submissions::append(date, part, outcome, result) {
+        eprintln!("could not write submission log: {e}");
+    }
+
+    if outcome == SubmissionOutcome::Correct {
+        store_answer(date, part, result);
+    }
+
+    Some(outcome)
+}
+
+/// Prints a status line to stdout, or to stderr if the output is machine-readable so it stays parseable.
+fn print_status(options: &RunOptions, line: &str) {
+    if options.format.is_human() {
+        println!("{line}");
+    } else {
+        eprintln!("{line}");
+    }
+}
+
+/// Stores a correct answer as accepted answer, unless one is recorded already.
+fn store_answer(date: AocDate, part: u8, result: &str) {
+    let stored = answers::read(date).and_then(|answers| {
+        let mut answers = answers.unwrap_or_default();
+
+        if answers.get(part).is_some() {
+            return Ok(());
+        }
+
+        answers.set(part, result.to_string());
+        answers::write(date, &answers)
+    });
+
+    if let Err(e) = stored {
+        eprintln!("could not store accepted answer: {e}");
     }
+}
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Answer, PartOutput};
+    use crate::parse::ParseError;
+
+    #[test]
+    fn converts_options() {
+        assert_eq!(Some(42).answer(), Answer::Solved(&42));
+        assert_eq!(None::<u32>.answer(), Answer::NotImplemented);
+        assert_eq!(None::<u32>.answer().status(), "unsolved");
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    #[test]
+    fn converts_results() {
+        let ok: Result<u32, String> = Ok(42);
+        assert_eq!(ok.answer(), Answer::Solved(&42));
+        assert!(ok.details("").is_empty());
+
+        let error: Result<u32, String> = Err("no path found".into());
+        assert_eq!(error.answer(), Answer::Error("no path found".into()));
+        assert_eq!(error.details(""), vec!["error: no path found"]);
+    }
+
+    #[test]
+    fn points_parse_errors_at_the_input() {
+        let input = "Game 1: 3 blue\nGame 2: 4 purple";
+        let line = input.lines().nth(1).unwrap();
+        let result: Result<u32, ParseError> =
+            Err(ParseError::new(line, &line[10..], "a color").on_line(2));
+
+        assert_eq!(result.answer().status(), "error");
+        assert_eq!(
+            result.details(input),
+            vec![
+                "error: line 2, column 11: expected a color, found `purple`",
+                "  |",
+                "2 | Game 2: 4 purple",
+                "  |           ^^^^^^",
+            ]
+        );
+    }
+
+    #[test]
+    fn keeps_details() {
+        let answer = Answer::Solved(42).with_details(["3 paths", "longest: 42"]);
+        assert_eq!(answer.answer(), Answer::Solved(&42));
+        assert_eq!(answer.details(""), vec!["3 paths", "longest: 42"]);
+
+        let answer = Answer::<u32>::NoAnswer.with_details(["the exit is unreachable"]);
+        assert_eq!(answer.answer().status(), "no_answer");
+    }
 }
@@ -0,0 +1,149 @@
+/// Summary statistics of benchmark samples.
+use std::time::Duration;
+
+/// Statistics over the samples of a benched solution part.
+///
+/// Percentiles are computed over all samples. Samples outside of Tukey's fences
+/// (more than 1.5 interquartile ranges above the third or below the first quartile)
+/// are counted as outliers and excluded from the mean and standard deviation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchStats {
+    pub samples: u128,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub max: Duration,
+    pub mean: Duration,
+    pub std_dev: Duration,
+    pub outliers: u128,
+}
+
+impl BenchStats {
+    /// Computes statistics from a list of samples, returns [`None`] if the list is empty.
+    #[must_use]
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut nanos: Vec<u128> = samples.iter().map(Duration::as_nanos).collect();
+        nanos.sort_unstable();
+
+        let q1 = percentile(&nanos, 0.25);
+        let q3 = percentile(&nanos, 0.75);
+        let fence = (q3 - q1) * 3 / 2;
+        let (lower, upper) = (q1.saturating_sub(fence), q3 + fence);
+
+        let inliers: Vec<u128> = nanos
+            .iter()
+            .copied()
+            .filter(|n| (lower..=upper).contains(n))
+            .collect();
+
+        let mean = inliers.iter().sum::<u128>() / inliers.len() as u128;
+
+        #[allow(clippy::cast_precision_loss)]
+        let variance = inliers
+            .iter()
+            .map(|&n| (n as f64 - mean as f64).powi(2))
+            .sum::<f64>()
+            / inliers.len() as f64;
+
+        Some(Self {
+            samples: nanos.len() as u128,
+            min: from_nanos(nanos[0]),
+            median: from_nanos(median(&nanos)),
+            p95: from_nanos(percentile(&nanos, 0.95)),
+            max: from_nanos(nanos[nanos.len() - 1]),
+            mean: from_nanos(mean),
+            std_dev: Duration::from_secs_f64(variance.sqrt() / 1e9),
+            outliers: (nanos.len() - inliers.len()) as u128,
+        })
+    }
+}
+
+/// The nearest-rank percentile of sorted values.
+fn percentile(sorted: &[u128], p: f64) -> u128 {
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    let rank = (p * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+fn median(sorted: &[u128]) -> u128 {
+    let mid = sorted.len() / 2;
+
+    if sorted.len() % 2 == 0 {
+        (sorted[mid - 1] + sorted[mid]) / 2
+    } else {
+        sorted[mid]
+    }
+}
+
+fn from_nanos(nanos: u128) -> Duration {
+    #[allow(clippy::cast_possible_truncation)]
+    Duration::from_nanos(nanos as u64)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::BenchStats;
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&n| Duration::from_nanos(n)).collect()
+    }
+
+    #[test]
+    fn no_samples() {
+        assert_eq!(BenchStats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn single_sample() {
+        let stats = BenchStats::from_samples(&nanos(&[42])).unwrap();
+        assert_eq!(stats.samples, 1);
+        assert_eq!(stats.min, Duration::from_nanos(42));
+        assert_eq!(stats.median, Duration::from_nanos(42));
+        assert_eq!(stats.p95, Duration::from_nanos(42));
+        assert_eq!(stats.max, Duration::from_nanos(42));
+        assert_eq!(stats.std_dev, Duration::ZERO);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn unsorted_samples() {
+        let stats = BenchStats::from_samples(&nanos(&[40, 10, 30, 20])).unwrap();
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.median, Duration::from_nanos(25));
+        assert_eq!(stats.p95, Duration::from_nanos(40));
+        assert_eq!(stats.max, Duration::from_nanos(40));
+        assert_eq!(stats.mean, Duration::from_nanos(25));
+        assert_eq!(stats.std_dev.as_nanos(), 11);
+    }
+
+    #[test]
+    fn excludes_outliers_from_mean() {
+        let mut samples = vec![100; 19];
+        samples.push(100_000);
+        let stats = BenchStats::from_samples(&nanos(&samples)).unwrap();
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.mean, Duration::from_nanos(100));
+        assert_eq!(stats.median, Duration::from_nanos(100));
+        assert_eq!(stats.p95, Duration::from_nanos(100));
+        assert_eq!(stats.max, Duration::from_nanos(100_000));
+    }
+
+    #[test]
+    fn percentiles() {
+        let samples: Vec<u64> = (1..=100).collect();
+        let stats = BenchStats::from_samples(&nanos(&samples)).unwrap();
+        assert_eq!(stats.median, Duration::from_nanos(50));
+        assert_eq!(stats.p95, Duration::from_nanos(95));
+        assert_eq!(stats.outliers, 0);
+    }
+}
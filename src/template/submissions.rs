@@ -0,0 +1,449 @@
+/// Module that interprets submission responses and keeps a log of all submissions in `data/<year>/submissions/<day>.log`.
+/// The log is used to refuse answers that are already known to be wrong before they are submitted again.
+use std::{
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::AocDate;
+
+/// The outcome of a submitted answer, as reported by the website.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubmissionOutcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// The answer is wrong, without a hint whether it is too high or too low.
+    Wrong,
+    /// An answer was submitted too recently, the wait time is included if the response contained it.
+    RateLimited(Option<Duration>),
+    /// The part was already solved.
+    AlreadySolved,
+}
+
+impl SubmissionOutcome {
+    /// Interprets the text of a submission response, returns [`None`] if the text is not recognized.
+    #[must_use]
+    pub fn from_message(message: &str) -> Option<Self> {
+        // the website wraps text in between sentences, normalize all whitespace.
+        let message = message.split_whitespace().collect::<Vec<_>>().join(" ");
+
+        if message.contains("That's the right answer") {
+            Some(Self::Correct)
+        } else if message.contains("your answer is too high") {
+            Some(Self::TooHigh)
+        } else if message.contains("your answer is too low") {
+            Some(Self::TooLow)
+        } else if message.contains("That's not the right answer") {
+            Some(Self::Wrong)
+        } else if message.contains("You gave an answer too recently") {
+            Some(Self::RateLimited(parse_wait_time(&message)))
+        } else if message.contains("Did you already complete it") {
+            Some(Self::AlreadySolved)
+        } else {
+            None
+        }
+    }
+
+    /// Whether the outcome proves that the answer is wrong.
+    #[must_use]
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Self::TooHigh | Self::TooLow | Self::Wrong)
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Correct => "correct",
+            Self::TooHigh => "too_high",
+            Self::TooLow => "too_low",
+            Self::Wrong => "wrong",
+            Self::RateLimited(_) => "rate_limited",
+            Self::AlreadySolved => "already_solved",
+        }
+    }
+
+    fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "correct" => Some(Self::Correct),
+            "too_high" => Some(Self::TooHigh),
+            "too_low" => Some(Self::TooLow),
+            "wrong" => Some(Self::Wrong),
+            "rate_limited" => Some(Self::RateLimited(None)),
+            "already_solved" => Some(Self::AlreadySolved),
+            _ => None,
+        }
+    }
+}
+
+impl Display for SubmissionOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::TooHigh => write!(f, "too high"),
+            Self::TooLow => write!(f, "too low"),
+            Self::Wrong => write!(f, "wrong"),
+            Self::RateLimited(Some(wait)) => write!(f, "rate limited, wait {wait:?}"),
+            Self::RateLimited(None) => write!(f, "rate limited"),
+            Self::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+/// Parses wait times like "You have 1m 4s left to wait."
+fn parse_wait_time(message: &str) -> Option<Duration> {
+    let (_, rest) = message.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+
+    wait.split_whitespace()
+        .map(|part| {
+            let unit_start = part.find(|c: char| !c.is_ascii_digit())?;
+            let value: u64 = part[..unit_start].parse().ok()?;
+
+            match &part[unit_start..] {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// A submitted answer and its outcome.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    /// Seconds since the unix epoch at the time of the submission.
+    pub timestamp: u64,
+    pub part: u8,
+    pub outcome: SubmissionOutcome,
+    pub answer: String,
+}
+
+/// The result of checking an answer against earlier submissions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    /// Nothing speaks against submitting the answer.
+    Ok,
+    /// The part was already solved with this answer.
+    AlreadyCorrect,
+    /// The part was already solved with a different answer.
+    AlreadySolved { answer: String },
+    /// The same answer was submitted before and was wrong.
+    KnownWrong { outcome: SubmissionOutcome },
+    /// The answer is not below an answer that was too high or not above an answer that was too low.
+    ContradictsBound {
+        outcome: SubmissionOutcome,
+        answer: String,
+    },
+}
+
+impl Check {
+    /// Whether the answer should not be submitted at all.
+    #[must_use]
+    pub fn is_refusal(&self) -> bool {
+        matches!(
+            self,
+            Check::AlreadyCorrect | Check::AlreadySolved { .. } | Check::KnownWrong { .. }
+        )
+    }
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Check::Ok => Ok(()),
+            Check::AlreadyCorrect => write!(f, "this answer was already accepted."),
+            Check::AlreadySolved { answer } => {
+                write!(f, "this part was already solved with answer {answer}.")
+            }
+            Check::KnownWrong { outcome } => {
+                write!(f, "this answer was submitted before and was {outcome}.")
+            }
+            Check::ContradictsBound { outcome, answer } => {
+                write!(
+                    f,
+                    "answer {answer} was {outcome}, this answer is likely wrong too."
+                )
+            }
+        }
+    }
+}
+
+/// Checks an answer against the earlier submissions of a part.
+#[must_use]
+pub fn check(submissions: &[Submission], part: u8, answer: &str) -> Check {
+    let submissions: Vec<&Submission> = submissions.iter().filter(|s| s.part == part).collect();
+
+    if let Some(correct) = submissions
+        .iter()
+        .find(|s| s.outcome == SubmissionOutcome::Correct)
+    {
+        return if correct.answer == answer {
+            Check::AlreadyCorrect
+        } else {
+            Check::AlreadySolved {
+                answer: correct.answer.clone(),
+            }
+        };
+    }
+
+    if let Some(wrong) = submissions
+        .iter()
+        .find(|s| s.answer == answer && s.outcome.is_wrong())
+    {
+        return Check::KnownWrong {
+            outcome: wrong.outcome,
+        };
+    }
+
+    let Ok(value) = answer.parse::<i128>() else {
+        return Check::Ok;
+    };
+
+    submissions
+        .iter()
+        .filter_map(|s| Some((s, s.answer.parse::<i128>().ok()?)))
+        .find(|(s, bound)| match s.outcome {
+            SubmissionOutcome::TooHigh => value >= *bound,
+            SubmissionOutcome::TooLow => value <= *bound,
+            _ => false,
+        })
+        .map_or(Check::Ok, |(s, _)| Check::ContradictsBound {
+            outcome: s.outcome,
+            answer: s.answer.clone(),
+        })
+}
+
+#[must_use]
+pub fn get_path(date: AocDate) -> String {
+    format!("data/{}/submissions/{}.log", date.year, date.day)
+}
+
+/// Reads all logged submissions of a day. Malformed lines are skipped.
+pub fn read(date: AocDate) -> io::Result<Vec<Submission>> {
+    let path = get_path(date);
+
+    if !Path::new(&path).exists() {
+        return Ok(vec![]);
+    }
+
+    Ok(fs::read_to_string(path)?
+        .lines()
+        .filter_map(parse_line)
+        .collect())
+}
+
+/// Appends a submission to the log of a day.
+pub fn append(date: AocDate, part: u8, outcome: SubmissionOutcome, answer: &str) -> io::Result<()> {
+    let path = get_path(date);
+
+    if let Some(dir) = Path::new(&path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let submission = Submission {
+        timestamp: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs()),
+        part,
+        outcome,
+        answer: answer.to_string(),
+    };
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+
+    writeln!(file, "{}", serialize(&submission))
+}
+
+fn serialize(submission: &Submission) -> String {
+    format!(
+        "{} {} {} {}",
+        submission.timestamp,
+        submission.part,
+        submission.outcome.as_str(),
+        submission.answer.escape_default()
+    )
+}
+
+fn parse_line(line: &str) -> Option<Submission> {
+    let mut fields = line.splitn(4, ' ');
+
+    Some(Submission {
+        timestamp: fields.next()?.parse().ok()?,
+        part: fields.next()?.parse().ok()?,
+        outcome: SubmissionOutcome::from_str(fields.next()?)?,
+        answer: unescape(fields.next()?),
+    })
+}
+
+/// Reverses the escaping of [`str::escape_default`] for the escapes that can occur in an answer.
+fn unescape(s: &str) -> String {
+    let mut unescaped = String::new();
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('t') => unescaped.push('\t'),
+            Some('r') => unescaped.push('\r'),
+            Some('u') => {
+                let code: String = chars.by_ref().skip(1).take_while(|&c| c != '}').collect();
+                if let Some(c) = u32::from_str_radix(&code, 16).ok().and_then(char::from_u32) {
+                    unescaped.push(c);
+                }
+            }
+            Some(c) => unescaped.push(c),
+            None => {}
+        }
+    }
+
+    unescaped
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{check, parse_line, serialize, Check, Submission, SubmissionOutcome};
+
+    fn submission(part: u8, outcome: SubmissionOutcome, answer: &str) -> Submission {
+        Submission {
+            timestamp: 1,
+            part,
+            outcome,
+            answer: answer.into(),
+        }
+    }
+
+    #[test]
+    fn parses_outcomes() {
+        let cases = [
+            (
+                "That's the right answer!  You are one gold star closer.",
+                SubmissionOutcome::Correct,
+            ),
+            (
+                "That's not the right answer; your answer is too high.  If you're stuck",
+                SubmissionOutcome::TooHigh,
+            ),
+            (
+                "That's not the right answer; your answer is too low.",
+                SubmissionOutcome::TooLow,
+            ),
+            (
+                "That's not the right answer.  If you're stuck, make sure",
+                SubmissionOutcome::Wrong,
+            ),
+            (
+                "You don't seem to be solving the right level.  Did you already complete it?",
+                SubmissionOutcome::AlreadySolved,
+            ),
+        ];
+
+        for (message, outcome) in cases {
+            assert_eq!(SubmissionOutcome::from_message(message), Some(outcome));
+        }
+
+        assert_eq!(SubmissionOutcome::from_message("Something else."), None);
+    }
+
+    #[test]
+    fn parses_rate_limits() {
+        assert_eq!(
+            SubmissionOutcome::from_message(
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 4s left to wait."
+            ),
+            Some(SubmissionOutcome::RateLimited(Some(Duration::from_secs(64))))
+        );
+        assert_eq!(
+            SubmissionOutcome::from_message(
+                "You gave an answer too recently.\nYou have 34s left\nto wait."
+            ),
+            Some(SubmissionOutcome::RateLimited(Some(Duration::from_secs(
+                34
+            ))))
+        );
+        assert_eq!(
+            SubmissionOutcome::from_message("You gave an answer too recently."),
+            Some(SubmissionOutcome::RateLimited(None))
+        );
+    }
+
+    #[test]
+    fn roundtrips_log_lines() {
+        let submissions = [
+            submission(1, SubmissionOutcome::TooHigh, "1234"),
+            submission(2, SubmissionOutcome::Correct, "multi word\nanswer \\ ü"),
+        ];
+
+        for s in submissions {
+            assert_eq!(parse_line(&serialize(&s)), Some(s));
+        }
+
+        assert_eq!(parse_line("1 1 unknown 42"), None);
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let log = [submission(1, SubmissionOutcome::Wrong, "42")];
+        assert_eq!(
+            check(&log, 1, "42"),
+            Check::KnownWrong {
+                outcome: SubmissionOutcome::Wrong
+            }
+        );
+        assert_eq!(check(&log, 1, "43"), Check::Ok);
+        assert_eq!(check(&log, 2, "42"), Check::Ok);
+        assert!(check(&log, 1, "42").is_refusal());
+    }
+
+    #[test]
+    fn refuses_solved_parts() {
+        let log = [
+            submission(1, SubmissionOutcome::TooLow, "10"),
+            submission(1, SubmissionOutcome::Correct, "42"),
+        ];
+        assert_eq!(check(&log, 1, "42"), Check::AlreadyCorrect);
+        assert_eq!(
+            check(&log, 1, "41"),
+            Check::AlreadySolved {
+                answer: "42".into()
+            }
+        );
+    }
+
+    #[test]
+    fn warns_about_contradicted_bounds() {
+        let log = [
+            submission(1, SubmissionOutcome::TooHigh, "100"),
+            submission(1, SubmissionOutcome::TooLow, "10"),
+            submission(1, SubmissionOutcome::RateLimited(None), "5"),
+        ];
+        assert_eq!(check(&log, 1, "50"), Check::Ok);
+        assert_eq!(
+            check(&log, 1, "150"),
+            Check::ContradictsBound {
+                outcome: SubmissionOutcome::TooHigh,
+                answer: "100".into()
+            }
+        );
+        assert_eq!(
+            check(&log, 1, "5"),
+            Check::ContradictsBound {
+                outcome: SubmissionOutcome::TooLow,
+                answer: "10".into()
+            }
+        );
+        assert!(!check(&log, 1, "5").is_refusal());
+        assert_eq!(check(&log, 1, "abc"), Check::Ok);
+    }
+}
@@ -0,0 +1,344 @@
+/// Time budgets for solution parts.
+///
+/// Solutions run in-process and can't be interrupted, so a solution with a budget runs on a worker thread
+/// while the calling thread watches the clock. Only the first run of a stage is budgeted, benchmark samples
+/// are not. A part that exceeds its budget is reported as timed out and its worker is abandoned: it stops
+/// printing and benching, but the part itself keeps running in the background until it returns or the
+/// process exits. The remaining part runs on a new worker.
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use crate::template::capture;
+use crate::template::registry::SolutionRunner;
+use crate::template::runner::{self, Answer, PartResult, RunOptions};
+use crate::AocDate;
+
+/// The stack size of worker threads, the same as the main thread has on most platforms.
+pub(crate) const WORKER_STACK_SIZE: usize = 8 * 1024 * 1024;
+
+/// Parses a duration like `10s`, `1.5s`, `500ms` or `2m`. A number without a unit is in seconds.
+pub fn parse_duration(s: &str) -> Result<Duration, String> {
+    let invalid = || format!("invalid duration `{s}`, expected e.g. `10s`, `500ms` or `2m`");
+
+    let split = s
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(s.len());
+    let (value, unit) = s.split_at(split);
+    let value: f64 = value.parse().map_err(|_| invalid())?;
+
+    let seconds = match unit {
+        "" | "s" => value,
+        "ms" => value / 1000.0,
+        "m" | "min" => value * 60.0,
+        _ => return Err(invalid()),
+    };
+
+    Duration::try_from_secs_f64(seconds).map_err(|_| invalid())
+}
+
+/// A stage of a solution that the budget applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Part(u8),
+}
+
+enum Event {
+    Started(Stage),
+    /// The first run of the running stage finished, the rest of the stage isn't budgeted.
+    Disarmed,
+    Finished(Box<PartResult>),
+}
+
+/// The connection of a worker thread to the thread that watches it.
+struct Watcher {
+    events: Sender<Event>,
+    cancelled: Arc<AtomicBool>,
+}
+
+thread_local! {
+    static WATCHER: RefCell<Option<Watcher>> = const { RefCell::new(None) };
+}
+
+/// Workers that were abandoned because their part ran out of time.
+static ABANDONED: Mutex<Vec<JoinHandle<()>>> = Mutex::new(Vec::new());
+
+/// The number of parts that timed out and still run in the background, they compete with benchmarks for the CPU.
+#[must_use]
+pub fn running_in_background() -> usize {
+    let mut abandoned = ABANDONED.lock().unwrap_or_else(PoisonError::into_inner);
+    abandoned.retain(|worker| !worker.is_finished());
+    abandoned.len()
+}
+
+/// Whether the current thread is a worker that was abandoned because it ran out of time.
+#[must_use]
+pub fn is_cancelled() -> bool {
+    WATCHER.with_borrow(|watcher| {
+        watcher
+            .as_ref()
+            .is_some_and(|watcher| watcher.cancelled.load(Ordering::Relaxed))
+    })
+}
+
+/// Tells the watching thread that a stage started, its budget starts now.
+pub fn started(stage: Stage) {
+    send(Event::Started(stage));
+}
+
+/// Tells the watching thread that the first run of the current stage finished, e.g. before it's benched.
+pub fn disarm() {
+    send(Event::Disarmed);
+}
+
+/// Tells the watching thread that a part finished.
+pub fn finished(result: &PartResult) {
+    send(Event::Finished(Box::new(result.clone())));
+}
+
+fn send(event: Event) {
+    WATCHER.with_borrow(|watcher| {
+        if let Some(watcher) = watcher {
+            // the watching thread stops listening once the worker was abandoned.
+            let _ = watcher.events.send(event);
+        }
+    });
+}
+
+/// Runs a solution with a time budget for its parse stage and each of its parts.
+pub fn run(
+    runner: SolutionRunner,
+    date: AocDate,
+    input: &str,
+    options: &RunOptions,
+    budget: Duration,
+) -> [PartResult; 2] {
+    let mut results: [Option<PartResult>; 2] = [None, None];
+    let mut only_part = options.part;
+
+    loop {
+        let options = RunOptions {
+            part: only_part,
+            ..*options
+        };
+
+        let timed_out = watch(runner, date, input, &options, budget, &mut results);
+
+        match results.iter().position(Option::is_none) {
+            Some(missing) if timed_out && only_part.is_none() => {
+                only_part = u8::try_from(missing + 1).ok();
+            }
+            _ => break,
+        }
+    }
+
+    let mut part = 0;
+    results.map(|result| {
+        part += 1;
+        // a part is only missing if its worker panicked or it was skipped.
+        result.unwrap_or_else(|| {
+            let answer = match options.part {
+                Some(only) if only != part => Answer::NotImplemented,
+                _ => Answer::Error("the solution panicked".into()),
+            };
+            runner::placeholder(date, part, answer, Duration::ZERO, None)
+        })
+    })
+}
+
+/// Runs the solution on a worker thread and collects the results of its parts, returns whether a stage timed out.
+fn watch(
+    runner: SolutionRunner,
+    date: AocDate,
+    input: &str,
+    options: &RunOptions,
+    budget: Duration,
+    results: &mut [Option<PartResult>; 2],
+) -> bool {
+    let (events, receiver) = mpsc::channel();
+    let cancelled = Arc::new(AtomicBool::new(false));
+
+    let watcher = Watcher {
+        events,
+        cancelled: Arc::clone(&cancelled),
+    };
+    let (input, worker_options) = (input.to_owned(), *options);
+    let output = capture::current();
+
+    let spawned = thread::Builder::new()
+        .name(format!("day {}", date.day))
+        .stack_size(WORKER_STACK_SIZE)
+        .spawn(move || {
+            WATCHER.set(Some(watcher));
+            capture::set_current(output);
+            runner(&input, &worker_options);
+        });
+
+    let worker = match spawned {
+        Ok(worker) => worker,
+        Err(e) => {
+            eprintln!("could not start a worker thread: {e}");
+            return false;
+        }
+    };
+
+    let mut running: Option<(Stage, Instant)> = None;
+
+    loop {
+        let event = match running {
+            Some((_, deadline)) => {
+                receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+            }
+            None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+
+        match event {
+            Ok(Event::Started(stage)) => running = Some((stage, Instant::now() + budget)),
+            Ok(Event::Disarmed) => running = None,
+            Ok(Event::Finished(result)) => {
+                running = None;
+                let index = usize::from(result.part) - 1;
+                results[index] = Some(*result);
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                cancelled.store(true, Ordering::Relaxed);
+
+                let Some((stage, _)) = running else {
+                    return false;
+                };
+
+                // a parse stage that times out takes the parts that depend on it along.
+                let parts = match stage {
+                    Stage::Parse => options.part.map_or(vec![1, 2], |part| vec![part]),
+                    Stage::Part(part) => vec![part],
+                };
+
+                for part in parts {
+                    let result = runner::placeholder(date, part, Answer::TimedOut, budget, None);
+                    runner::report(&result, options);
+                    results[usize::from(part) - 1] = Some(result);
+                }
+
+                ABANDONED
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .push(worker);
+
+                return true;
+            }
+            // the worker finished, or it panicked.
+            Err(RecvTimeoutError::Disconnected) => return false,
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{parse_duration, run, running_in_background};
+    use crate::template::runner::{run_part, Answer, PartResult, RunOptions};
+    use crate::{day, year, AocDate};
+
+    const DATE: AocDate = AocDate::new(year!(2023), day!(1));
+
+    fn options() -> RunOptions {
+        RunOptions {
+            quiet: true,
+            ..RunOptions::default()
+        }
+    }
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(parse_duration("10s"), Ok(Duration::from_secs(10)));
+        assert_eq!(parse_duration("10"), Ok(Duration::from_secs(10)));
+        assert_eq!(parse_duration("1.5s"), Ok(Duration::from_millis(1500)));
+        assert_eq!(parse_duration("250ms"), Ok(Duration::from_millis(250)));
+        assert_eq!(parse_duration("2m"), Ok(Duration::from_secs(120)));
+        assert!(parse_duration("10h").is_err());
+        assert!(parse_duration("s").is_err());
+        assert!(parse_duration("-1s").is_err());
+    }
+
+    fn hangs_in_part_one(input: &str, options: &RunOptions) -> [PartResult; 2] {
+        let slow = |input: &str| {
+            std::thread::sleep(Duration::from_secs(5));
+            Some(input.len())
+        };
+
+        [
+            run_part(slow, input, DATE, 1, options),
+            run_part(|input: &str| Some(input.len() * 2), input, DATE, 2, options),
+        ]
+    }
+
+    #[test]
+    fn abandons_parts_that_run_out_of_time() {
+        let [one, two] = run(
+            hangs_in_part_one,
+            DATE,
+            "abc",
+            &options(),
+            Duration::from_millis(50),
+        );
+
+        assert_eq!(one.answer, Answer::TimedOut);
+        assert_eq!(one.duration, Duration::from_millis(50));
+        assert_eq!(two.answer, Answer::Solved("6".into()));
+        assert!(running_in_background() >= 1);
+    }
+
+    #[test]
+    fn keeps_parts_within_budget() {
+        let [one, two] = run(
+            |input, options| {
+                [
+                    run_part(|input: &str| Some(input.len()), input, DATE, 1, options),
+                    run_part(|_: &str| None::<u32>, input, DATE, 2, options),
+                ]
+            },
+            DATE,
+            "abc",
+            &options(),
+            Duration::from_secs(5),
+        );
+
+        assert_eq!(one.answer, Answer::Solved("3".into()));
+        assert_eq!(two.answer, Answer::NotImplemented);
+    }
+
+    #[test]
+    fn budgets_the_first_run_of_benched_parts() {
+        let options = RunOptions {
+            time: true,
+            part: Some(1),
+            ..options()
+        };
+
+        let [one, _] = run(
+            |input, options| {
+                let slow = |input: &str| {
+                    std::thread::sleep(Duration::from_millis(20));
+                    Some(input.len())
+                };
+
+                [
+                    run_part(slow, input, DATE, 1, options),
+                    run_part(slow, input, DATE, 2, options),
+                ]
+            },
+            DATE,
+            "abc",
+            &options,
+            Duration::from_millis(100),
+        );
+
+        assert_eq!(one.answer, Answer::Solved("3".into()));
+        assert!(one.samples >= 10);
+    }
+}